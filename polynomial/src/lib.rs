@@ -1,23 +1,97 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+// the float routines lean on std's math functions
+#[cfg(feature = "std")]
+pub mod roots;
+
+pub mod modular;
+pub mod sparse;
+
+use alloc::string::{String, ToString};
+use alloc::vec;
+use alloc::vec::Vec;
+use core::cmp;
+use core::fmt::Write;
+#[cfg(feature = "std")]
+use num::Complex;
 use num::Num;
-use std::cmp;
-use std::fmt::Write;
 
+// operands longer than this are multiplied with karatsuba
+const KARATSUBA_THRESHOLD: usize = 32;
+
+#[derive(Clone)]
 pub struct Polynomial<T> {
     coefs_: Vec<T>,
 }
 
+#[derive(Debug, PartialEq)]
+pub enum InterpolationError<T> {
+    EmptyPoints,
+    DuplicateX(T),
+}
+
+impl<T: core::fmt::Display> core::fmt::Display for InterpolationError<T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            InterpolationError::EmptyPoints => write!(f, "no points to interpolate"),
+            InterpolationError::DuplicateX(x) => write!(f, "duplicate x-coordinate {}", x),
+        }
+    }
+}
+
+impl<T: core::fmt::Debug + core::fmt::Display> core::error::Error for InterpolationError<T> {}
+
+#[derive(Debug, PartialEq)]
+pub enum FitError {
+    Underdetermined,
+    Singular,
+}
+
+impl core::fmt::Display for FitError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            FitError::Underdetermined => write!(f, "not enough points for the requested degree"),
+            FitError::Singular => write!(f, "normal equations are singular"),
+        }
+    }
+}
+
+impl core::error::Error for FitError {}
+
+#[derive(Debug, PartialEq)]
+pub enum DeflationError {
+    NotARoot,
+}
+
+impl core::fmt::Display for DeflationError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            DeflationError::NotARoot => write!(f, "remainder is not close to zero"),
+        }
+    }
+}
+
+impl core::error::Error for DeflationError {}
+
 impl<T> Polynomial<T>
 where
-    T: Copy
-        + std::cmp::PartialEq
+    T: Clone
+        + core::cmp::PartialEq
         + Default
         + Num
-        + std::ops::Add<Output = T>
-        + std::ops::Mul<Output = T>
-        + std::ops::AddAssign<<T as std::ops::Mul>::Output>
-        + std::ops::Neg<Output = T>,
+        + core::ops::Add<Output = T>
+        + core::ops::Mul<Output = T>
+        + core::ops::AddAssign<<T as core::ops::Mul>::Output>
+        + core::ops::Neg<Output = T>,
 {
     pub fn new(mut coefs: Vec<T>) -> Self {
+        // no coefficients at all is the zero polynomial
+        if coefs.is_empty() {
+            coefs.push(T::default());
+        }
+
         let coefs_ = coefs.clone();
         let mut c_it = coefs_.iter().rev().peekable();
         let mut cnt = 0;
@@ -39,34 +113,180 @@ where
         }
     }
 
-    fn single_term_poly(points: &[(T, T)], idx: usize) -> Polynomial<T> {
-        let mut term = Polynomial::new(vec![T::one()]);
-        let (xi, yi) = points[idx];
+    pub fn constant(c: T) -> Self {
+        Polynomial::new(vec![c])
+    }
+
+    pub fn zero() -> Self {
+        Polynomial::new(vec![T::default()])
+    }
+
+    pub fn one() -> Self {
+        Polynomial::new(vec![T::one()])
+    }
+
+    pub fn monomial(coef: T, degree: usize) -> Self {
+        if coef == T::default() {
+            return Polynomial::zero();
+        }
+
+        let mut coefs = vec![T::default(); degree + 1];
+        coefs[degree] = coef;
+        Polynomial::new(coefs)
+    }
+
+    pub fn from_roots(roots: &[T]) -> Self {
+        roots
+            .iter()
+            .map(|r| Polynomial::new(vec![-r.clone(), T::one()]))
+            .product()
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.coefs_.iter().all(|coef| *coef == T::default())
+    }
+
+    pub fn degree(&self) -> Option<usize> {
+        if self.is_zero() {
+            return None;
+        }
+        Some(self.coefs_.len() - 1)
+    }
+
+    pub fn coefficient(&self, i: usize) -> T {
+        self.coefs_.get(i).cloned().unwrap_or_default()
+    }
+
+    pub fn coefficients(&self) -> &[T] {
+        &self.coefs_
+    }
+
+    pub fn num_terms(&self) -> usize {
+        self.coefs_.iter().filter(|c| **c != T::zero()).count()
+    }
+
+    // (exponent, coefficient) pairs for the nonzero terms, lowest first
+    pub fn terms(&self) -> impl Iterator<Item = (usize, T)> + '_ {
+        self.coefs_
+            .iter()
+            .enumerate()
+            .filter(|(_, c)| **c != T::zero())
+            .map(|(i, c)| (i, c.clone()))
+    }
+
+    pub fn set_coefficient(&mut self, i: usize, value: T) {
+        if i >= self.coefs_.len() {
+            self.coefs_.resize(i + 1, T::default());
+        }
+        self.coefs_[i] = value;
+
+        // re-trim in case the leading coefficient was zeroed
+        *self = Polynomial::new(core::mem::take(&mut self.coefs_));
+    }
+
+    pub fn leading_coefficient(&self) -> T {
+        self.coefs_.last().cloned().unwrap_or_default()
+    }
+
+    pub fn constant_term(&self) -> T {
+        self.coefs_.first().cloned().unwrap_or_default()
+    }
+
+    fn single_term_poly(
+        points: &[(T, T)],
+        idx: usize,
+    ) -> Result<Polynomial<T>, InterpolationError<T>> {
+        let mut term = Polynomial::one();
+        let (xi, yi) = &points[idx];
 
-        for (j, p) in points.iter().enumerate() {
+        for (j, (xj, _)) in points.iter().enumerate() {
             if j == idx {
                 continue;
             }
 
-            let xj = p.0;
+            if xi == xj {
+                return Err(InterpolationError::DuplicateX(xi.clone()));
+            }
+            let denom = xi.clone() - xj.clone();
             term = term.mul(&Polynomial::new(vec![
-                -xj / (xi - xj),
-                T::one() / (xi - xj),
+                -xj.clone() / denom.clone(),
+                T::one() / denom,
             ]));
         }
 
-        term.mul(&Polynomial::new(vec![yi]))
+        Ok(term.mul(&Polynomial::constant(yi.clone())))
     }
 
+    fn lagrange(points: &[(T, T)]) -> Result<Self, InterpolationError<T>> {
+        (0..points.len())
+            .map(|idx| Self::single_term_poly(points, idx))
+            .sum()
+    }
+
+    // panics on duplicate x-coordinates, see try_interpolate_from
     pub fn interpolate_from(points: Vec<(T, T)>) -> Self {
-        // TODO: check points vec not empty
-        // TODO: check points are unique
-        let terms: Vec<Polynomial<T>> = (0..points.len())
-            .map(|idx| Self::single_term_poly(&points, idx))
-            .collect();
-        terms
-            .iter()
-            .fold(Polynomial::new(vec![T::default()]), |acc, x| acc.add(x))
+        Self::lagrange(&points)
+            .unwrap_or_else(|_| panic!("interpolation points have duplicate x-coordinates"))
+    }
+
+    pub fn try_interpolate_from(points: Vec<(T, T)>) -> Result<Self, InterpolationError<T>> {
+        if points.is_empty() {
+            return Err(InterpolationError::EmptyPoints);
+        }
+        Self::lagrange(&points)
+    }
+
+    // rows are [1, x, x^2, ..., x^degree] for each x
+    pub fn vandermonde(xs: &[T], degree: usize) -> Vec<Vec<T>> {
+        xs.iter()
+            .map(|x| {
+                let mut row = Vec::with_capacity(degree + 1);
+                let mut pow = T::one();
+                for _ in 0..=degree {
+                    row.push(pow.clone());
+                    pow = pow * x.clone();
+                }
+                row
+            })
+            .collect()
+    }
+
+    fn newton(points: Vec<(T, T)>) -> Result<Self, InterpolationError<T>> {
+        let (xs, mut c): (Vec<T>, Vec<T>) = points.into_iter().unzip();
+
+        // divided differences, computed in place; every pair of points meets
+        // in some denominator, so this catches all duplicates
+        for j in 1..c.len() {
+            for i in (j..c.len()).rev() {
+                if xs[i] == xs[i - j] {
+                    return Err(InterpolationError::DuplicateX(xs[i].clone()));
+                }
+                c[i] = (c[i].clone() - c[i - 1].clone()) / (xs[i].clone() - xs[i - j].clone());
+            }
+        }
+
+        // expand the newton form from the innermost term outwards
+        let mut poly = Polynomial::zero();
+        for (x, c) in xs.into_iter().zip(c).rev() {
+            poly = poly
+                .mul(&Polynomial::new(vec![-x, T::one()]))
+                .add(&Polynomial::constant(c));
+        }
+
+        Ok(poly)
+    }
+
+    // panics on duplicate x-coordinates, see try_interpolate_newton
+    pub fn interpolate_newton(points: Vec<(T, T)>) -> Self {
+        Self::newton(points)
+            .unwrap_or_else(|_| panic!("interpolation points have duplicate x-coordinates"))
+    }
+
+    pub fn try_interpolate_newton(points: Vec<(T, T)>) -> Result<Self, InterpolationError<T>> {
+        if points.is_empty() {
+            return Err(InterpolationError::EmptyPoints);
+        }
+        Self::newton(points)
     }
 
     pub fn add(&self, other: &Polynomial<T>) -> Self {
@@ -78,10 +298,10 @@ where
             let mut lhs = T::default();
 
             if let Some(coef) = self.coefs_.get(i) {
-                rhs = *coef;
+                rhs = coef.clone();
             }
             if let Some(coef) = other.coefs_.get(i) {
-                lhs = *coef;
+                lhs = coef.clone();
             }
 
             *coef = rhs + lhs;
@@ -90,127 +310,3891 @@ where
         Polynomial::new(res_coefs)
     }
 
+    pub fn sub(&self, other: &Polynomial<T>) -> Self {
+        let max_len = cmp::max(self.coefs_.len(), other.coefs_.len());
+        let mut res_coefs = vec![T::default(); max_len];
+
+        for (i, coef) in res_coefs.iter_mut().enumerate() {
+            let mut lhs = T::default();
+            let mut rhs = T::default();
+
+            if let Some(coef) = self.coefs_.get(i) {
+                lhs = coef.clone();
+            }
+            if let Some(coef) = other.coefs_.get(i) {
+                rhs = coef.clone();
+            }
+
+            *coef = lhs - rhs;
+        }
+
+        Polynomial::new(res_coefs)
+    }
+
+    pub fn neg(&self) -> Self {
+        Polynomial::new(self.coefs_.iter().map(|coef| -coef.clone()).collect())
+    }
+
     pub fn mul(&self, other: &Polynomial<T>) -> Self {
-        let mut res_coefs = vec![T::default(); self.coefs_.len() + other.coefs_.len() - 1];
+        // also keeps an empty coefficient vector from underflowing the result length
+        if self.is_zero() || other.is_zero() {
+            return Polynomial::zero();
+        }
+        if self.coefs_.len() > KARATSUBA_THRESHOLD && other.coefs_.len() > KARATSUBA_THRESHOLD {
+            return self.mul_karatsuba(other);
+        }
+        Polynomial::new(Self::schoolbook_mul(&self.coefs_, &other.coefs_))
+    }
 
-        for (i, a) in self.coefs_.iter().enumerate() {
-            for (j, b) in other.coefs_.iter().enumerate() {
-                res_coefs[i + j] += *a * *b;
+    pub fn mul_karatsuba(&self, other: &Polynomial<T>) -> Self {
+        if self.is_zero() || other.is_zero() {
+            return Polynomial::zero();
+        }
+        Polynomial::new(Self::karatsuba_mul(&self.coefs_, &other.coefs_))
+    }
+
+    fn schoolbook_mul(a: &[T], b: &[T]) -> Vec<T> {
+        let mut res_coefs = vec![T::default(); a.len() + b.len() - 1];
+
+        for (i, a) in a.iter().enumerate() {
+            for (j, b) in b.iter().enumerate() {
+                res_coefs[i + j] += a.clone() * b.clone();
+            }
+        }
+
+        res_coefs
+    }
+
+    fn karatsuba_mul(a: &[T], b: &[T]) -> Vec<T> {
+        if a.len() <= KARATSUBA_THRESHOLD || b.len() <= KARATSUBA_THRESHOLD {
+            return Self::schoolbook_mul(a, b);
+        }
+
+        // a = a0 + a1 x^m, b = b0 + b1 x^m
+        let m = cmp::min(a.len(), b.len()) / 2;
+        let (a0, a1) = a.split_at(m);
+        let (b0, b1) = b.split_at(m);
+
+        let slice_add = |x: &[T], y: &[T]| -> Vec<T> {
+            (0..cmp::max(x.len(), y.len()))
+                .map(|i| {
+                    x.get(i).cloned().unwrap_or_default() + y.get(i).cloned().unwrap_or_default()
+                })
+                .collect()
+        };
+
+        let z0 = Self::karatsuba_mul(a0, b0);
+        let z2 = Self::karatsuba_mul(a1, b1);
+        // z1 = (a0 + a1)(b0 + b1) - z0 - z2, and is at least as long as both
+        let mut z1 = Self::karatsuba_mul(&slice_add(a0, a1), &slice_add(b0, b1));
+        for (i, c) in z0.iter().enumerate() {
+            z1[i] = z1[i].clone() - c.clone();
+        }
+        for (i, c) in z2.iter().enumerate() {
+            z1[i] = z1[i].clone() - c.clone();
+        }
+
+        let mut res_coefs = vec![T::default(); a.len() + b.len() - 1];
+        for (i, c) in z0.into_iter().enumerate() {
+            res_coefs[i] += c;
+        }
+        for (i, c) in z1.into_iter().enumerate() {
+            res_coefs[i + m] += c;
+        }
+        for (i, c) in z2.into_iter().enumerate() {
+            res_coefs[i + 2 * m] += c;
+        }
+
+        res_coefs
+    }
+
+    // product as truncated power series, terms of degree n and above are
+    // never computed
+    pub fn mul_mod_xn(&self, other: &Polynomial<T>, n: usize) -> Self {
+        let len = cmp::min(n, self.coefs_.len() + other.coefs_.len() - 1);
+        let mut res_coefs = vec![T::default(); len];
+
+        for (i, a) in self.coefs_.iter().enumerate().take(len) {
+            for (j, b) in other.coefs_.iter().enumerate().take(len - i) {
+                res_coefs[i + j] += a.clone() * b.clone();
             }
         }
 
         Polynomial::new(res_coefs)
     }
 
-    pub fn eval_at(&self, x: T) -> T
-    where
-        <T as std::ops::Mul>::Output: std::ops::Add<T>,
-    {
-        let mut sum = T::default();
+    // newton iteration g <- g (2 - f g), each step doubles the number of
+    // correct terms; None when the constant term is zero
+    pub fn series_inverse(&self, n: usize) -> Option<Self> {
+        if self.coefs_[0] == T::zero() {
+            return None;
+        }
 
-        for coef in self.coefs_.iter().rev() {
-            sum = sum * x + *coef;
+        let two = T::one() + T::one();
+        let mut g = Polynomial::constant(T::one() / self.coefs_[0].clone());
+        let mut k = 1;
+
+        while k < n {
+            k *= 2;
+            let fg = self.mul_mod_xn(&g, k);
+            g = g.mul_mod_xn(&fg.neg().add_scalar(two.clone()), k);
         }
 
-        sum
+        Some(g.mul_mod_xn(&Polynomial::one(), n))
     }
-}
 
-impl<T: std::fmt::Display> std::fmt::Debug for Polynomial<T> {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        let mut poly_str = "Poly: ".to_string();
-        for (pow, coef) in self.coefs_.iter().enumerate() {
-            write!(poly_str, "({}x^{}) + ", coef, pow)?;
+    pub fn pow(&self, exp: u32) -> Self {
+        let mut res = Polynomial::one();
+        let mut base = self.clone();
+        let mut exp = exp;
+
+        // exponentiation by squaring
+        while exp > 0 {
+            if exp & 1 == 1 {
+                res = res.mul(&base);
+            }
+            exp >>= 1;
+            if exp > 0 {
+                base = base.mul(&base);
+            }
         }
 
-        write!(f, "{}", poly_str.trim_end_matches(" + "))
+        res
     }
-}
 
-impl<T: std::cmp::PartialEq> PartialEq for Polynomial<T> {
-    fn eq(&self, other: &Self) -> bool {
-        if self.coefs_.len() != other.coefs_.len() {
-            return false;
+    pub fn div_rem(&self, divisor: &Polynomial<T>) -> (Polynomial<T>, Polynomial<T>) {
+        if divisor.is_zero() {
+            panic!("polynomial division by the zero polynomial");
         }
-        for i in 0..self.coefs_.len() {
-            if self.coefs_[i] != other.coefs_[i] {
-                return false;
+
+        let d = &divisor.coefs_;
+        let mut rem = self.coefs_.clone();
+        if rem.len() < d.len() {
+            return (Polynomial::zero(), Polynomial::new(rem));
+        }
+
+        let lead = divisor.leading_coefficient();
+        let mut quot = vec![T::default(); rem.len() - d.len() + 1];
+
+        // eliminate the leading term of the remainder one degree at a time
+        for i in (0..quot.len()).rev() {
+            let c = rem[i + d.len() - 1].clone() / lead.clone();
+            for (j, coef) in d.iter().enumerate() {
+                rem[i + j] = rem[i + j].clone() - c.clone() * coef.clone();
             }
+            quot[i] = c;
         }
-        true
+
+        rem.truncate(d.len() - 1);
+
+        (Polynomial::new(quot), Polynomial::new(rem))
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+    pub fn synthetic_divide(&self, root: T) -> (Polynomial<T>, T) {
+        let n = self.coefs_.len();
+        if n < 2 {
+            return (Polynomial::zero(), self.constant_term());
+        }
 
-    #[test]
-    fn it_work() {
-        let poly = Polynomial::new(vec![0.0, 1.0, 2.0, 3.0]);
-        println!("{:?}", poly);
-        let poly1 = Polynomial::new(vec![0.0, 1.0, 2.0, 3.0, 0.0, 0.0]);
-        println!("{:?}", poly1);
-        let poly1 = Polynomial::new(vec![0.0, 1.0, 2.0, 3.0, 0.0]);
-        println!("{:?}", poly1);
-        let poly1 = Polynomial::new(vec![0.0, 0.0, 0.0]);
-        println!("{:?}", poly1);
-        let poly1 = Polynomial::new(vec![1.0]);
-        println!("{:?}", poly1);
-        let poly1 = Polynomial::new(vec![0.0]);
-        println!("{:?}", poly1);
+        let mut quot = vec![T::default(); n - 1];
+        let mut carry = T::default();
+        for i in (1..n).rev() {
+            carry = carry * root.clone() + self.coefs_[i].clone();
+            quot[i - 1] = carry.clone();
+        }
+
+        (Polynomial::new(quot), carry * root + self.coefs_[0].clone())
     }
 
-    #[test]
-    fn sum() {
-        let poly1 = Polynomial::new(vec![0.0, 1.0, 2.0]);
-        let poly2 = Polynomial::new(vec![1.0, 2.0, 3.0]);
-        let poly3 = poly1.add(&poly2);
-        assert_eq!(poly3, Polynomial::new(vec![1.0, 3.0, 5.0]));
+    pub fn rem(&self, divisor: &Polynomial<T>) -> Polynomial<T> {
+        self.div_rem(divisor).1
+    }
 
-        let poly1 = Polynomial::new(vec![1.0]);
-        let poly2 = Polynomial::new(vec![0.0, 1.0, 3.0]);
-        let poly3 = poly1.add(&poly2);
-        assert_eq!(poly3, Polynomial::new(vec![1.0, 1.0, 3.0]));
-        let poly3 = poly2.add(&poly1);
-        assert_eq!(poly3, Polynomial::new(vec![1.0, 1.0, 3.0]));
+    // the zero polynomial has no leading coefficient to divide by and is
+    // returned unchanged
+    pub fn to_monic(&self) -> Self {
+        if self.is_zero() {
+            return self.clone();
+        }
+        self.scale(T::one() / self.leading_coefficient())
+    }
 
-        let poly1 = Polynomial::new(vec![0]);
-        let poly2 = Polynomial::new(vec![0, 1]);
-        let poly3 = poly1.add(&poly2);
-        assert_eq!(poly3, Polynomial::new(vec![0, 1]));
+    pub fn is_monic(&self) -> bool {
+        self.leading_coefficient() == T::one()
     }
 
-    #[test]
-    fn mul() {
-        let poly1 = Polynomial::new(vec![1.0, 2.0, 3.0]);
-        let poly2 = Polynomial::new(vec![1.0, 2.0, 3.0]);
-        let poly3 = poly1.mul(&poly2);
-        assert_eq!(poly3, Polynomial::new(vec![1.0, 4.0, 10.0, 12.0, 9.0]));
+    pub fn gcd(&self, other: &Polynomial<T>) -> Polynomial<T> {
+        let mut a = self.clone();
+        let mut b = other.clone();
 
-        let poly1 = Polynomial::new(vec![1, 2, 3]);
-        let poly2 = Polynomial::new(vec![0]);
-        let poly3 = poly1.mul(&poly2);
-        assert_eq!(poly3, Polynomial::new(vec![0]));
+        while !b.is_zero() {
+            let r = a.rem(&b);
+            a = b;
+            b = r;
+        }
 
-        let poly1 = Polynomial::new(vec![1, 2, 3, 4]);
-        let poly2 = Polynomial::new(vec![0, 1, 2, 3]);
-        let poly3 = poly1.mul(&poly2);
-        assert_eq!(poly3, Polynomial::new(vec![0, 1, 4, 10, 16, 17, 12]));
+        // gcd(0, 0) is the zero polynomial, anything else is normalized to monic
+        a.to_monic()
+    }
 
-        let poly1 = Polynomial::new(vec![1, 2, 3]);
-        let poly2 = Polynomial::new(vec![1, 2, 3, 4, 5]);
-        let poly3 = poly1.mul(&poly2);
-        assert_eq!(poly3, Polynomial::new(vec![1, 4, 10, 16, 22, 22, 15]));
+    pub fn lcm(&self, other: &Polynomial<T>) -> Polynomial<T> {
+        if self.is_zero() || other.is_zero() {
+            return Polynomial::zero();
+        }
+        self.mul(other).div_rem(&self.gcd(other)).0.to_monic()
     }
 
-    #[test]
-    fn eval() {
-        let poly = Polynomial::new(vec![1, 2, 3]);
-        assert_eq!(poly.eval_at(8), 209);
+    // returns (g, s, t) with s * self + t * other == g and g the monic gcd
+    pub fn extended_gcd(
+        &self,
+        other: &Polynomial<T>,
+    ) -> (Polynomial<T>, Polynomial<T>, Polynomial<T>) {
+        let (mut r0, mut r1) = (self.clone(), other.clone());
+        let (mut s0, mut s1) = (Polynomial::one(), Polynomial::zero());
+        let (mut t0, mut t1) = (Polynomial::zero(), Polynomial::one());
 
-        let poly = Polynomial::new(vec![1i64, 4, 10, 16, 22, 22, 15]);
-        assert_eq!(poly.eval_at(29), 9389554026);
+        while !r1.is_zero() {
+            let (q, r) = r0.div_rem(&r1);
+            r0 = core::mem::replace(&mut r1, r);
+            let s = s0.sub(&q.mul(&s1));
+            s0 = core::mem::replace(&mut s1, s);
+            let t = t0.sub(&q.mul(&t1));
+            t0 = core::mem::replace(&mut t1, t);
+        }
+
+        if r0.is_zero() {
+            return (r0, s0, t0);
+        }
+        let inv = T::one() / r0.leading_coefficient();
+        (r0.scale(inv.clone()), s0.scale(inv.clone()), t0.scale(inv))
+    }
+
+    // yun's algorithm; returns monic squarefree factors with their
+    // multiplicities, the leading coefficient itself is not included
+    pub fn squarefree_decomposition(&self) -> Vec<(Polynomial<T>, usize)> {
+        let mut factors = Vec::new();
+        if self.degree().unwrap_or(0) == 0 {
+            return factors;
+        }
+
+        let f = self.to_monic();
+        let df = f.derivative();
+        let a0 = f.gcd(&df);
+        let mut b = f.div_rem(&a0).0;
+        let c = df.div_rem(&a0).0;
+        let mut d = c.sub(&b.derivative());
+        let mut i = 1;
+
+        while b.degree().unwrap_or(0) > 0 {
+            let a = b.gcd(&d);
+            b = b.div_rem(&a).0;
+            let c = d.div_rem(&a).0;
+            d = c.sub(&b.derivative());
+            if a.degree().unwrap_or(0) > 0 {
+                factors.push((a, i));
+            }
+            i += 1;
+        }
+
+        factors
+    }
+
+    // euclidean remainder sequence, tracking the sign and leading
+    // coefficient factors dropped at each step; zero exactly when the two
+    // polynomials share a root
+    pub fn resultant(&self, other: &Polynomial<T>) -> T {
+        if self.is_zero() || other.is_zero() {
+            return T::zero();
+        }
+
+        let mut a = self.clone();
+        let mut b = other.clone();
+        let mut res = T::one();
+
+        loop {
+            let m = a.degree().unwrap();
+            let n = b.degree().unwrap();
+            if n == 0 {
+                return res * num::pow(b.leading_coefficient(), m);
+            }
+
+            let r = a.rem(&b);
+            if r.is_zero() {
+                return T::zero();
+            }
+            let k = r.degree().unwrap();
+            if m * n % 2 == 1 {
+                res = -res;
+            }
+            res = res * num::pow(b.leading_coefficient(), m - k);
+            a = b;
+            b = r;
+        }
+    }
+
+    // zero exactly when the polynomial has a repeated root
+    pub fn discriminant(&self) -> T {
+        let n = match self.degree() {
+            Some(n) if n > 0 => n,
+            _ => panic!("discriminant of a constant polynomial is undefined"),
+        };
+
+        let disc = self.resultant(&self.derivative()) / self.leading_coefficient();
+        if n * (n - 1) / 2 % 2 == 1 {
+            -disc
+        } else {
+            disc
+        }
+    }
+
+    // ones on the subdiagonal, and the negated monic coefficients in the last
+    // column, so the eigenvalues are the roots of the polynomial
+    pub fn companion_matrix(&self) -> Vec<Vec<T>> {
+        if self.is_zero() {
+            panic!("companion matrix of the zero polynomial is undefined");
+        }
+
+        let n = self.coefs_.len() - 1;
+        let lead = self.leading_coefficient();
+        let mut m = vec![vec![T::default(); n]; n];
+
+        for (i, row) in m.iter_mut().enumerate() {
+            if i > 0 {
+                row[i - 1] = T::one();
+            }
+            row[n - 1] = -self.coefs_[i].clone() / lead.clone();
+        }
+
+        m
+    }
+
+    pub fn derivative(&self) -> Self {
+        if self.coefs_.len() < 2 {
+            return Polynomial::zero();
+        }
+
+        let mut n = T::default();
+        let res_coefs = self
+            .coefs_
+            .iter()
+            .skip(1)
+            .map(|coef| {
+                n += T::one();
+                coef.clone() * n.clone()
+            })
+            .collect();
+
+        Polynomial::new(res_coefs)
+    }
+
+    pub fn integral(&self, constant: T) -> Self {
+        let mut res_coefs = vec![constant];
+        let mut n = T::default();
+
+        for coef in self.coefs_.iter() {
+            n += T::one();
+            res_coefs.push(coef.clone() / n.clone());
+        }
+
+        Polynomial::new(res_coefs)
+    }
+
+    pub fn integrate_between(&self, a: T, b: T) -> T {
+        let antiderivative = self.integral(T::default());
+        antiderivative.eval_at(b) - antiderivative.eval_at(a)
+    }
+
+    pub fn compose(&self, inner: &Polynomial<T>) -> Self {
+        let mut acc = Polynomial::zero();
+
+        // horner's scheme with polynomial arithmetic
+        for coef in self.coefs_.iter().rev() {
+            acc = acc.mul(inner).add(&Polynomial::constant(coef.clone()));
+        }
+
+        acc
+    }
+
+    // coefficients of self(x + a); grows binomially with the degree, so for
+    // floats large degrees or shifts lose precision quickly
+    pub fn shift(&self, a: T) -> Self {
+        self.compose(&Polynomial::new(vec![a, T::one()]))
+    }
+
+    // p(x + 1) - p(x), one degree lower
+    pub fn forward_difference(&self) -> Self {
+        self.shift(T::one()).sub(self)
+    }
+
+    // coefficients c_k with p(x) = sum of c_k * binomial(x, k), where c_k is
+    // the k-th forward difference at 0; integer valued polynomials get
+    // integer c_k even when their usual coefficients are fractions
+    pub fn to_binomial_basis(&self) -> Vec<T> {
+        let mut res = vec![self.coefs_[0].clone()];
+        let mut diff = self.clone();
+
+        while diff.degree().unwrap_or(0) > 0 {
+            diff = diff.forward_difference();
+            res.push(diff.coefs_[0].clone());
+        }
+
+        res
+    }
+
+    pub fn from_binomial_basis(coefs: &[T]) -> Self {
+        // binomial(x, k) = x (x - 1) ... (x - k + 1) / k!, the falling
+        // factorial has integer coefficients so only c_k gets divided
+        let mut k_t = T::zero();
+        let mut factorial = T::one();
+        let mut falling = Polynomial::one();
+        let mut sum = Polynomial::zero();
+        for (k, coef) in coefs.iter().enumerate() {
+            if k > 0 {
+                falling = falling.mul(&Polynomial::new(vec![-k_t.clone(), T::one()]));
+                let next = k_t.clone() + T::one();
+                factorial = factorial * next.clone();
+                k_t = next;
+            }
+
+            // a remainder that divides to zero was dropped by a truncating
+            // division, so the result has fractional coefficients T can't
+            // hold; in a field the remainder is zero or a rounding residue
+            let weight = coef.clone() / factorial.clone();
+            let rem = coef.clone() - weight.clone() * factorial.clone();
+            if rem != T::zero() && rem / factorial.clone() == T::zero() {
+                panic!("result has fractional coefficients");
+            }
+            sum = sum.add(&falling.scale(weight));
+        }
+
+        sum
+    }
+
+    pub fn shift_up(&self, k: usize) -> Self {
+        if self.is_zero() {
+            return Polynomial::zero();
+        }
+
+        let mut res_coefs = vec![T::default(); k];
+        res_coefs.extend(self.coefs_.iter().cloned());
+        Polynomial::new(res_coefs)
+    }
+
+    // None when x^k does not divide the polynomial
+    pub fn shift_down(&self, k: usize) -> Option<Self> {
+        if self.coefs_.iter().take(k).any(|coef| *coef != T::default()) {
+            return None;
+        }
+        if k >= self.coefs_.len() {
+            return Some(Polynomial::zero());
+        }
+        Some(Polynomial::new(self.coefs_[k..].to_vec()))
+    }
+
+    // keeps the terms up to and including x^degree
+    pub fn truncate(&self, degree: usize) -> Self {
+        if degree + 1 >= self.coefs_.len() {
+            return self.clone();
+        }
+        Polynomial::new(self.coefs_[..=degree].to_vec())
+    }
+
+    pub fn add_scalar(&self, c: T) -> Self {
+        let mut res_coefs = self.coefs_.clone();
+        res_coefs[0] = res_coefs[0].clone() + c;
+        Polynomial::new(res_coefs)
+    }
+
+    // x^n * p(1/x); zero low-order coefficients end up on top and get trimmed,
+    // so reversing twice gives p divided by the largest x^k dividing it
+    pub fn reverse(&self) -> Self {
+        Polynomial::new(self.coefs_.iter().rev().cloned().collect())
+    }
+
+    pub fn scale(&self, k: T) -> Self {
+        Polynomial::new(
+            self.coefs_
+                .iter()
+                .map(|coef| coef.clone() * k.clone())
+                .collect(),
+        )
+    }
+
+    // descartes' rule of signs, an upper bound on the positive roots that is
+    // off by an even number; zero coefficients are skipped
+    pub fn descartes_sign_changes(&self) -> usize
+    where
+        T: core::cmp::PartialOrd,
+    {
+        let zero = T::zero();
+        let signs: Vec<bool> = self
+            .coefs_
+            .iter()
+            .filter(|c| **c != zero)
+            .map(|c| *c < zero)
+            .collect();
+        signs.windows(2).filter(|w| w[0] != w[1]).count()
+    }
+
+    // the same bound for the negative roots, through p(-x)
+    pub fn descartes_negative_sign_changes(&self) -> usize
+    where
+        T: core::cmp::PartialOrd,
+    {
+        let flipped = self
+            .coefs_
+            .iter()
+            .enumerate()
+            .map(|(i, c)| if i % 2 == 1 { -c.clone() } else { c.clone() })
+            .collect();
+        Polynomial::new(flipped).descartes_sign_changes()
+    }
+
+    pub fn map_coefficients<U, F>(&self, f: F) -> Polynomial<U>
+    where
+        F: FnMut(T) -> U,
+        U: Clone
+            + core::cmp::PartialEq
+            + Default
+            + Num
+            + core::ops::Add<Output = U>
+            + core::ops::Mul<Output = U>
+            + core::ops::AddAssign<<U as core::ops::Mul>::Output>
+            + core::ops::Neg<Output = U>,
+    {
+        Polynomial::new(self.coefs_.iter().cloned().map(f).collect())
+    }
+
+    pub fn cast<U>(&self) -> Polynomial<U>
+    where
+        U: From<T>
+            + Clone
+            + core::cmp::PartialEq
+            + Default
+            + Num
+            + core::ops::Add<Output = U>
+            + core::ops::Mul<Output = U>
+            + core::ops::AddAssign<<U as core::ops::Mul>::Output>
+            + core::ops::Neg<Output = U>,
+    {
+        self.map_coefficients(U::from)
+    }
+
+    pub fn eval_at(&self, x: T) -> T
+    where
+        <T as core::ops::Mul>::Output: core::ops::Add<T>,
+    {
+        let mut sum = T::default();
+
+        for coef in self.coefs_.iter().rev() {
+            sum = sum * x.clone() + coef.clone();
+        }
+
+        sum
+    }
+
+    // horner in the ring of x, e.g. complex numbers or dual numbers, with
+    // each coefficient converted into it on the way
+    pub fn eval_generic<U>(&self, x: U) -> U
+    where
+        U: From<T> + Clone + core::ops::Add<Output = U> + core::ops::Mul<Output = U>,
+    {
+        let mut coefs = self.coefs_.iter().rev();
+        let mut sum = U::from(coefs.next().unwrap().clone());
+
+        for coef in coefs {
+            sum = sum * x.clone() + U::from(coef.clone());
+        }
+
+        sum
+    }
+
+    pub fn eval_with_derivative(&self, x: T) -> (T, T) {
+        let mut sum = T::default();
+        let mut d = T::default();
+
+        // horner's recurrence for the derivative runs alongside the value
+        for coef in self.coefs_.iter().rev() {
+            d = d * x.clone() + sum.clone();
+            sum = sum * x.clone() + coef.clone();
+        }
+
+        (sum, d)
+    }
+
+    // forward-mode differentiation, x is seeded with derivative one
+    pub fn eval_dual(&self, x: T) -> (T, T) {
+        let res = self.eval_generic(Dual::new(x, T::one()));
+        (res.value, res.deriv)
+    }
+
+    pub fn eval_derivative_at(&self, x: T) -> T {
+        self.eval_with_derivative(x).1
+    }
+
+    // horner with matrix products, the coefficients enter as multiples of
+    // the identity
+    pub fn eval_at_matrix(&self, m: &[Vec<T>]) -> Vec<Vec<T>> {
+        let n = m.len();
+        if m.iter().any(|row| row.len() != n) {
+            panic!("matrix argument must be square");
+        }
+
+        let mut res = vec![vec![T::default(); n]; n];
+
+        for coef in self.coefs_.iter().rev() {
+            let mut next = vec![vec![T::default(); n]; n];
+            for (i, row) in next.iter_mut().enumerate() {
+                for (j, cell) in row.iter_mut().enumerate() {
+                    for (k, mk) in m.iter().enumerate() {
+                        *cell += res[i][k].clone() * mk[j].clone();
+                    }
+                }
+                row[i] += coef.clone();
+            }
+            res = next;
+        }
+
+        res
+    }
+
+    pub fn eval_all(&self, xs: &[T]) -> Vec<T> {
+        xs.iter().map(|x| self.eval_at(x.clone())).collect()
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T> Polynomial<T>
+where
+    T: num::Float + Default + core::ops::AddAssign,
+{
+    pub fn fit_least_squares(points: &[(T, T)], degree: usize) -> Result<Self, FitError> {
+        let n = degree + 1;
+        if points.len() < n {
+            return Err(FitError::Underdetermined);
+        }
+
+        // normal equations V^T V c = V^T y as an augmented matrix
+        let xs: Vec<T> = points.iter().map(|p| p.0).collect();
+        let v = Polynomial::vandermonde(&xs, degree);
+        let mut a = vec![vec![T::zero(); n + 1]; n];
+        for (row, (_, y)) in v.iter().zip(points) {
+            for i in 0..n {
+                for j in 0..n {
+                    a[i][j] += row[i] * row[j];
+                }
+                a[i][n] += row[i] * *y;
+            }
+        }
+
+        // gaussian elimination with partial pivoting
+        let scale = a.iter().flatten().fold(T::zero(), |m, v| m.max(v.abs()));
+        for col in 0..n {
+            let pivot = (col..n)
+                .max_by(|i, j| a[*i][col].abs().partial_cmp(&a[*j][col].abs()).unwrap())
+                .unwrap();
+            if a[pivot][col].abs() <= scale * T::epsilon() * T::from(n).unwrap() {
+                return Err(FitError::Singular);
+            }
+            a.swap(col, pivot);
+
+            let pivot_row = a[col].clone();
+            for row in a.iter_mut().skip(col + 1) {
+                let factor = row[col] / pivot_row[col];
+                for (v, p) in row.iter_mut().zip(pivot_row.iter()).skip(col) {
+                    *v = *v - factor * *p;
+                }
+            }
+        }
+
+        let mut coefs = vec![T::zero(); n];
+        for row in (0..n).rev() {
+            let mut sum = a[row][n];
+            for k in row + 1..n {
+                sum = sum - a[row][k] * coefs[k];
+            }
+            coefs[row] = sum / a[row][row];
+        }
+
+        Ok(Polynomial::new(coefs))
+    }
+
+    // runs newton's method from each guess in turn, deflating the polynomial
+    // by every root found; guesses that don't converge within max_iter are
+    // skipped, and complex roots are never found
+    pub fn real_roots_newton(&self, guesses: &[T], max_iter: usize, tol: T) -> Vec<T> {
+        let mut poly = self.clone();
+        let mut roots = Vec::new();
+
+        for guess in guesses {
+            if poly.degree().unwrap_or(0) == 0 {
+                break;
+            }
+
+            let mut x = *guess;
+            let mut converged = false;
+            for _ in 0..max_iter {
+                let (value, slope) = poly.eval_with_derivative(x);
+                if slope == T::zero() {
+                    break;
+                }
+
+                let step = value / slope;
+                x = x - step;
+                if step.abs() < tol {
+                    converged = true;
+                    break;
+                }
+            }
+
+            if converged {
+                roots.push(x);
+                poly = poly.synthetic_divide(x).0;
+            }
+        }
+
+        roots
+    }
+
+    // divides out (x - root); the remainder may only be as large as the
+    // rounding error horner's method could have made at root, bounded by
+    // 2 * degree * epsilon * sum |c_i| |root|^i
+    pub fn deflate(&self, root: T) -> Result<Self, DeflationError> {
+        let (quot, rem) = self.synthetic_divide(root);
+
+        let magnitude = self
+            .coefs_
+            .iter()
+            .rev()
+            .fold(T::zero(), |acc, coef| acc * root.abs() + coef.abs());
+        let degree = T::from(self.coefs_.len() - 1).unwrap();
+        let two = T::one() + T::one();
+        if rem.abs() > two * degree * T::epsilon() * magnitude {
+            return Err(DeflationError::NotARoot);
+        }
+
+        Ok(quot)
+    }
+}
+
+// in-place iterative cooley-tukey, a.len() must be a power of two
+#[cfg(feature = "std")]
+fn fft(a: &mut [Complex<f64>], invert: bool) {
+    let n = a.len();
+
+    let mut j = 0;
+    for i in 1..n {
+        let mut bit = n >> 1;
+        while j & bit != 0 {
+            j ^= bit;
+            bit >>= 1;
+        }
+        j ^= bit;
+        if i < j {
+            a.swap(i, j);
+        }
+    }
+
+    let mut len = 2;
+    while len <= n {
+        let sign = if invert { 1.0 } else { -1.0 };
+        let w_len = Complex::from_polar(1.0, sign * 2.0 * core::f64::consts::PI / len as f64);
+        for chunk in a.chunks_mut(len) {
+            let mut w = Complex::new(1.0, 0.0);
+            let (lo, hi) = chunk.split_at_mut(len / 2);
+            for (u, v) in lo.iter_mut().zip(hi.iter_mut()) {
+                let t = *v * w;
+                *v = *u - t;
+                *u += t;
+                w *= w_len;
+            }
+        }
+        len <<= 1;
+    }
+
+    if invert {
+        for x in a.iter_mut() {
+            *x /= n as f64;
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl Polynomial<f64> {
+    // T_{n+1} = 2x T_n - T_{n-1}
+    pub fn chebyshev_t(n: usize) -> Polynomial<f64> {
+        let mut prev = Polynomial::one();
+        if n == 0 {
+            return prev;
+        }
+
+        let mut cur = Polynomial::monomial(1.0, 1);
+        for _ in 1..n {
+            let next = cur.shift_up(1).scale(2.0).sub(&prev);
+            prev = cur;
+            cur = next;
+        }
+        cur
+    }
+
+    // clenshaw's recurrence for sum c_k T_k(x), without expanding the
+    // series into the monomial basis
+    pub fn eval_chebyshev(coeffs: &[f64], x: f64) -> f64 {
+        let (c0, rest) = match coeffs.split_first() {
+            Some(split) => split,
+            None => return 0.0,
+        };
+
+        let mut b1 = 0.0;
+        let mut b2 = 0.0;
+        for c in rest.iter().rev() {
+            let b = c + 2.0 * x * b1 - b2;
+            b2 = b1;
+            b1 = b;
+        }
+        c0 + x * b1 - b2
+    }
+
+    // interpolates f at the degree + 1 chebyshev nodes of [a, b], which is
+    // close to the minimax approximation, then maps the series from [-1, 1]
+    // back to [a, b] in the monomial basis
+    pub fn approximate_chebyshev<F: Fn(f64) -> f64>(
+        f: F,
+        degree: usize,
+        a: f64,
+        b: f64,
+    ) -> Polynomial<f64> {
+        if a >= b {
+            panic!("approximation interval must have a < b");
+        }
+
+        let n = degree + 1;
+        let angles: Vec<f64> = (0..n)
+            .map(|j| core::f64::consts::PI * (j as f64 + 0.5) / n as f64)
+            .collect();
+        let values: Vec<f64> = angles
+            .iter()
+            .map(|t| f((a + b) / 2.0 + (b - a) / 2.0 * t.cos()))
+            .collect();
+
+        // discrete orthogonality of the T_k at the nodes gives the series
+        // coefficients, with the constant term weighted by half
+        let mut series = Polynomial::zero();
+        for k in 0..n {
+            let weight = if k == 0 { 1.0 } else { 2.0 } / n as f64;
+            let sum: f64 = angles
+                .iter()
+                .zip(values.iter())
+                .map(|(t, v)| v * (k as f64 * t).cos())
+                .sum();
+            series = series.add(&Polynomial::chebyshev_t(k).scale(weight * sum));
+        }
+
+        series.compose(&Polynomial::new(vec![-(a + b) / (b - a), 2.0 / (b - a)]))
+    }
+
+    // distinct real roots in (a, b], from the drop in sign changes of the
+    // sturm sequence p, p', -rem(p, p'), ... between a and b
+    pub fn count_real_roots(&self, a: f64, b: f64) -> usize {
+        if self.is_zero() {
+            panic!("the zero polynomial has a root everywhere");
+        }
+
+        let mut seq = vec![self.clone(), self.derivative()];
+        while !seq[seq.len() - 1].is_zero() {
+            let prev = &seq[seq.len() - 2];
+            let rem = prev.rem(&seq[seq.len() - 1]);
+
+            // remainders at rounding level end the sequence like exact zeros;
+            // the division's rounding error grows with the degree
+            let scale = prev.coefs_.iter().fold(0.0, |m: f64, c| m.max(c.abs()));
+            let cutoff = 2.0 * prev.coefs_.len() as f64 * f64::EPSILON * scale;
+            if rem.coefs_.iter().all(|c| c.abs() <= cutoff) {
+                break;
+            }
+            // only the sign matters, so keep the entries well scaled
+            let lead = rem.leading_coefficient().abs();
+            seq.push(rem.scale(-1.0 / lead));
+        }
+
+        let sign_changes = |x: f64| {
+            let values: Vec<f64> = seq
+                .iter()
+                .map(|p| p.eval_at(x))
+                .filter(|v| *v != 0.0)
+                .collect();
+            values
+                .windows(2)
+                .filter(|w| (w[0] < 0.0) != (w[1] < 0.0))
+                .count()
+        };
+
+        sign_changes(a).saturating_sub(sign_changes(b))
+    }
+
+    // bonnet's recurrence, (n + 1) P_{n+1} = (2n + 1) x P_n - n P_{n-1}
+    pub fn legendre(n: usize) -> Polynomial<f64> {
+        let mut prev = Polynomial::one();
+        if n == 0 {
+            return prev;
+        }
+
+        let mut cur = Polynomial::monomial(1.0, 1);
+        for k in 1..n {
+            let k = k as f64;
+            let next = cur
+                .shift_up(1)
+                .scale(2.0 * k + 1.0)
+                .sub(&prev.scale(k))
+                .scale(1.0 / (k + 1.0));
+            prev = cur;
+            cur = next;
+        }
+        cur
+    }
+
+    // H_{n+1} = 2x H_n - 2n H_{n-1}
+    pub fn hermite_physicists(n: usize) -> Polynomial<f64> {
+        let mut prev = Polynomial::one();
+        if n == 0 {
+            return prev;
+        }
+
+        let mut cur = Polynomial::monomial(2.0, 1);
+        for k in 1..n {
+            let next = cur.shift_up(1).scale(2.0).sub(&prev.scale(2.0 * k as f64));
+            prev = cur;
+            cur = next;
+        }
+        cur
+    }
+
+    // He_{n+1} = x He_n - n He_{n-1}, the monic variant
+    pub fn hermite_probabilists(n: usize) -> Polynomial<f64> {
+        let mut prev = Polynomial::one();
+        if n == 0 {
+            return prev;
+        }
+
+        let mut cur = Polynomial::monomial(1.0, 1);
+        for k in 1..n {
+            let next = cur.shift_up(1).sub(&prev.scale(k as f64));
+            prev = cur;
+            cur = next;
+        }
+        cur
+    }
+
+    pub fn approx_eq(&self, other: &Self, epsilon: f64) -> bool {
+        let len = cmp::max(self.coefs_.len(), other.coefs_.len());
+        (0..len).all(|i| (self.coefficient(i) - other.coefficient(i)).abs() <= epsilon)
+    }
+
+    // O(n log n) multiplication; every coefficient picks up a rounding error
+    // of roughly eps * log(n) * max|a| * max|b|, so tiny coefficients next to
+    // huge ones lose their relative precision and exact zeros may come back
+    // as small nonzero values
+    pub fn mul_fft(&self, other: &Polynomial<f64>) -> Polynomial<f64> {
+        if self.is_zero() || other.is_zero() {
+            return Polynomial::zero();
+        }
+
+        let res_len = self.coefs_.len() + other.coefs_.len() - 1;
+        let n = res_len.next_power_of_two();
+
+        let mut fa: Vec<Complex<f64>> = self.coefs_.iter().map(|c| Complex::new(*c, 0.0)).collect();
+        let mut fb: Vec<Complex<f64>> =
+            other.coefs_.iter().map(|c| Complex::new(*c, 0.0)).collect();
+        fa.resize(n, Complex::new(0.0, 0.0));
+        fb.resize(n, Complex::new(0.0, 0.0));
+
+        fft(&mut fa, false);
+        fft(&mut fb, false);
+        for (a, b) in fa.iter_mut().zip(fb.iter()) {
+            *a *= *b;
+        }
+        fft(&mut fa, true);
+
+        Polynomial::new(fa.iter().take(res_len).map(|c| c.re).collect())
+    }
+
+    // picks the sign in the quadratic formula that avoids cancellation, and
+    // recovers the second root from vieta's c / a = r1 * r2
+    pub fn roots_quadratic(&self) -> Option<(Complex<f64>, Complex<f64>)> {
+        if self.degree() != Some(2) {
+            return None;
+        }
+
+        let (c, b, a) = (self.coefs_[0], self.coefs_[1], self.coefs_[2]);
+        let disc = b * b - 4.0 * a * c;
+
+        if disc < 0.0 {
+            let re = -b / (2.0 * a);
+            let im = (-disc).sqrt() / (2.0 * a);
+            return Some((Complex::new(re, im), Complex::new(re, -im)));
+        }
+
+        let q = -(b + disc.sqrt().copysign(b)) / 2.0;
+        if q == 0.0 {
+            return Some((Complex::new(0.0, 0.0), Complex::new(0.0, 0.0)));
+        }
+        Some((Complex::new(q / a, 0.0), Complex::new(c / q, 0.0)))
+    }
+}
+
+impl Polynomial<i64> {
+    // always nonnegative, and 0 only for the zero polynomial
+    pub fn content(&self) -> i64 {
+        self.coefs_
+            .iter()
+            .fold(0, |acc, coef| num::integer::gcd(acc, *coef))
+    }
+
+    pub fn primitive_part(&self) -> Self {
+        let content = self.content();
+        if content == 0 {
+            return self.clone();
+        }
+        Polynomial::new(self.coefs_.iter().map(|coef| coef / content).collect())
+    }
+
+    // canonical residues in [0, modulus), i.e. the image in Z/nZ[x]
+    pub fn mod_coefficients(&self, modulus: i64) -> Self {
+        if modulus <= 0 {
+            panic!("modulus must be positive");
+        }
+        Polynomial::new(
+            self.coefs_
+                .iter()
+                .map(|coef| coef.rem_euclid(modulus))
+                .collect(),
+        )
+    }
+
+    // lc(divisor)^(m - n + 1) * self = quot * divisor + rem with deg rem < n,
+    // so no step ever divides
+    pub fn pseudo_div_rem(&self, divisor: &Polynomial<i64>) -> (Polynomial<i64>, Polynomial<i64>) {
+        let n = match divisor.degree() {
+            Some(n) => n,
+            None => panic!("polynomial division by the zero polynomial"),
+        };
+        let m = match self.degree() {
+            Some(m) if m >= n => m,
+            _ => return (Polynomial::zero(), self.clone()),
+        };
+
+        let lead = divisor.leading_coefficient();
+        let mut quot = Polynomial::zero();
+        let mut rem = self.clone();
+        let mut e = (m - n + 1) as u32;
+
+        while let Some(d) = rem.degree().filter(|d| *d >= n) {
+            let term = Polynomial::monomial(rem.leading_coefficient(), d - n);
+            quot = quot.scale(lead).add(&term);
+            rem = rem.scale(lead).sub(&term.mul(divisor));
+            e -= 1;
+        }
+
+        // top up to the full power when the remainder dropped degrees early
+        let factor = lead.pow(e);
+        (quot.scale(factor), rem.scale(factor))
+    }
+
+    // p / q is a root exactly when qx - p divides the polynomial over the
+    // integers (gauss's lemma). dividing from the top, every intermediate is
+    // q times a cofactor coefficient, so checked arithmetic only gives up on
+    // cofactors that don't fit in i128
+    fn divides_exactly(coefs: &[i64], p: i128, q: i128) -> bool {
+        let mut b: i128 = 0;
+        for coef in coefs[1..].iter().rev() {
+            let t = match p
+                .checked_mul(b)
+                .and_then(|pb| pb.checked_add(*coef as i128))
+            {
+                Some(t) => t,
+                None => return false,
+            };
+            if t % q != 0 {
+                return false;
+            }
+            b = t / q;
+        }
+
+        p.checked_mul(b)
+            .and_then(|pb| pb.checked_add(coefs[0] as i128))
+            .is_some_and(|r| r == 0)
+    }
+
+    // rational root theorem; roots are returned as (p, q) in lowest terms with
+    // q > 0, sorted ascending
+    pub fn rational_roots(&self) -> Vec<(i64, i64)> {
+        if self.is_zero() {
+            return Vec::new();
+        }
+
+        let mut roots = Vec::new();
+
+        // a zero constant term means 0 is a root, divide out x^k first
+        let shift = self.coefs_.iter().take_while(|coef| **coef == 0).count();
+        if shift > 0 {
+            roots.push((0, 1));
+        }
+        let coefs = &self.coefs_[shift..];
+        let n = coefs.len() - 1;
+
+        // every divisor is a product of prime powers from the factorization
+        let divisors = |v: i64| -> Vec<u64> {
+            let mut primes = modular::prime_factors(v.unsigned_abs());
+            primes.sort();
+            let mut res = vec![1];
+            for group in primes.chunk_by(|a, b| a == b) {
+                let prime = group[0];
+                res = res
+                    .iter()
+                    .flat_map(|d| (0..=group.len() as u32).map(move |e| d * prime.pow(e)))
+                    .collect();
+            }
+            res
+        };
+
+        for p in divisors(coefs[0]) {
+            for q in divisors(coefs[n]) {
+                if num::integer::gcd(p, q) != 1 {
+                    continue;
+                }
+                for p in [p as i128, -(p as i128)] {
+                    let (Ok(p64), Ok(q64)) = (i64::try_from(p), i64::try_from(q)) else {
+                        continue;
+                    };
+                    if Self::divides_exactly(coefs, p, q as i128) {
+                        roots.push((p64, q64));
+                    }
+                }
+            }
+        }
+
+        roots.sort_by(|a, b| (a.0 as i128 * b.1 as i128).cmp(&(b.0 as i128 * a.1 as i128)));
+        roots
+    }
+}
+
+// integer counterparts of eval_at and mul that report overflow as None
+// instead of wrapping
+impl<T> Polynomial<T>
+where
+    T: Clone
+        + core::cmp::PartialEq
+        + Default
+        + Num
+        + core::ops::Add<Output = T>
+        + core::ops::Mul<Output = T>
+        + core::ops::AddAssign<<T as core::ops::Mul>::Output>
+        + core::ops::Neg<Output = T>
+        + num::CheckedAdd
+        + num::CheckedMul,
+{
+    pub fn checked_eval_at(&self, x: T) -> Option<T> {
+        let mut sum = T::default();
+
+        for coef in self.coefs_.iter().rev() {
+            sum = sum.checked_mul(&x)?.checked_add(coef)?;
+        }
+
+        Some(sum)
+    }
+
+    pub fn checked_mul(&self, other: &Self) -> Option<Self> {
+        if self.is_zero() || other.is_zero() {
+            return Some(Polynomial::zero());
+        }
+
+        let mut coefs = vec![T::default(); self.coefs_.len() + other.coefs_.len() - 1];
+
+        for (i, a) in self.coefs_.iter().enumerate() {
+            for (j, b) in other.coefs_.iter().enumerate() {
+                coefs[i + j] = coefs[i + j].checked_add(&a.checked_mul(b)?)?;
+            }
+        }
+
+        Some(Polynomial::new(coefs))
+    }
+}
+
+// a value together with its derivative; arithmetic follows the sum and
+// product rules, so evaluating at Dual::new(x, 1) also differentiates
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Dual<T> {
+    pub value: T,
+    pub deriv: T,
+}
+
+impl<T> Dual<T> {
+    pub fn new(value: T, deriv: T) -> Self {
+        Dual { value, deriv }
+    }
+}
+
+// constants have zero derivative
+impl<T: num::Zero> From<T> for Dual<T> {
+    fn from(value: T) -> Self {
+        Dual::new(value, T::zero())
+    }
+}
+
+impl<T: core::ops::Add<Output = T>> core::ops::Add for Dual<T> {
+    type Output = Dual<T>;
+
+    fn add(self, other: Dual<T>) -> Dual<T> {
+        Dual::new(self.value + other.value, self.deriv + other.deriv)
+    }
+}
+
+impl<T> core::ops::Mul for Dual<T>
+where
+    T: Clone + core::ops::Add<Output = T> + core::ops::Mul<Output = T>,
+{
+    type Output = Dual<T>;
+
+    fn mul(self, other: Dual<T>) -> Dual<T> {
+        Dual::new(
+            self.value.clone() * other.value.clone(),
+            self.value * other.deriv + self.deriv * other.value,
+        )
+    }
+}
+
+// evaluates the interpolating polynomial through points in O(n) per query,
+// without expanding it into coefficients
+pub struct BarycentricInterpolator<T> {
+    xs: Vec<T>,
+    ys: Vec<T>,
+    weights: Vec<T>,
+}
+
+impl<T> BarycentricInterpolator<T>
+where
+    T: Clone
+        + core::cmp::PartialEq
+        + Default
+        + Num
+        + core::ops::Add<Output = T>
+        + core::ops::Mul<Output = T>
+        + core::ops::AddAssign<<T as core::ops::Mul>::Output>
+        + core::ops::Neg<Output = T>,
+{
+    pub fn new(points: Vec<(T, T)>) -> Result<Self, InterpolationError<T>> {
+        if points.is_empty() {
+            return Err(InterpolationError::EmptyPoints);
+        }
+
+        let (xs, ys): (Vec<T>, Vec<T>) = points.into_iter().unzip();
+        let mut weights = Vec::with_capacity(xs.len());
+
+        for (j, xj) in xs.iter().enumerate() {
+            let mut prod = T::one();
+            for (k, xk) in xs.iter().enumerate() {
+                if k == j {
+                    continue;
+                }
+                if xj == xk {
+                    return Err(InterpolationError::DuplicateX(xj.clone()));
+                }
+                prod = prod * (xj.clone() - xk.clone());
+            }
+            weights.push(T::one() / prod);
+        }
+
+        Ok(BarycentricInterpolator { xs, ys, weights })
+    }
+
+    pub fn eval_at(&self, x: T) -> T {
+        let mut num = T::default();
+        let mut den = T::default();
+
+        for ((xj, yj), wj) in self.xs.iter().zip(self.ys.iter()).zip(self.weights.iter()) {
+            // the formula divides by zero at the nodes themselves
+            if x == *xj {
+                return yj.clone();
+            }
+
+            let t = wj.clone() / (x.clone() - xj.clone());
+            num += t.clone() * yj.clone();
+            den += t;
+        }
+
+        num / den
+    }
+}
+
+impl<T> core::ops::Add for Polynomial<T>
+where
+    T: Clone
+        + core::cmp::PartialEq
+        + Default
+        + Num
+        + core::ops::Add<Output = T>
+        + core::ops::Mul<Output = T>
+        + core::ops::AddAssign<<T as core::ops::Mul>::Output>
+        + core::ops::Neg<Output = T>,
+{
+    type Output = Polynomial<T>;
+
+    fn add(self, other: Polynomial<T>) -> Polynomial<T> {
+        Polynomial::add(&self, &other)
+    }
+}
+
+impl<T> core::ops::Add<&Polynomial<T>> for Polynomial<T>
+where
+    T: Clone
+        + core::cmp::PartialEq
+        + Default
+        + Num
+        + core::ops::Add<Output = T>
+        + core::ops::Mul<Output = T>
+        + core::ops::AddAssign<<T as core::ops::Mul>::Output>
+        + core::ops::Neg<Output = T>,
+{
+    type Output = Polynomial<T>;
+
+    fn add(self, other: &Polynomial<T>) -> Polynomial<T> {
+        Polynomial::add(&self, other)
+    }
+}
+
+impl<T> core::ops::Add<Polynomial<T>> for &Polynomial<T>
+where
+    T: Clone
+        + core::cmp::PartialEq
+        + Default
+        + Num
+        + core::ops::Add<Output = T>
+        + core::ops::Mul<Output = T>
+        + core::ops::AddAssign<<T as core::ops::Mul>::Output>
+        + core::ops::Neg<Output = T>,
+{
+    type Output = Polynomial<T>;
+
+    fn add(self, other: Polynomial<T>) -> Polynomial<T> {
+        Polynomial::add(self, &other)
+    }
+}
+
+impl<T> core::ops::Add<&Polynomial<T>> for &Polynomial<T>
+where
+    T: Clone
+        + core::cmp::PartialEq
+        + Default
+        + Num
+        + core::ops::Add<Output = T>
+        + core::ops::Mul<Output = T>
+        + core::ops::AddAssign<<T as core::ops::Mul>::Output>
+        + core::ops::Neg<Output = T>,
+{
+    type Output = Polynomial<T>;
+
+    fn add(self, other: &Polynomial<T>) -> Polynomial<T> {
+        Polynomial::add(self, other)
+    }
+}
+
+impl<T> core::ops::Mul for Polynomial<T>
+where
+    T: Clone
+        + core::cmp::PartialEq
+        + Default
+        + Num
+        + core::ops::Add<Output = T>
+        + core::ops::Mul<Output = T>
+        + core::ops::AddAssign<<T as core::ops::Mul>::Output>
+        + core::ops::Neg<Output = T>,
+{
+    type Output = Polynomial<T>;
+
+    fn mul(self, other: Polynomial<T>) -> Polynomial<T> {
+        Polynomial::mul(&self, &other)
+    }
+}
+
+impl<T> core::ops::Mul<&Polynomial<T>> for Polynomial<T>
+where
+    T: Clone
+        + core::cmp::PartialEq
+        + Default
+        + Num
+        + core::ops::Add<Output = T>
+        + core::ops::Mul<Output = T>
+        + core::ops::AddAssign<<T as core::ops::Mul>::Output>
+        + core::ops::Neg<Output = T>,
+{
+    type Output = Polynomial<T>;
+
+    fn mul(self, other: &Polynomial<T>) -> Polynomial<T> {
+        Polynomial::mul(&self, other)
+    }
+}
+
+impl<T> core::ops::Mul<Polynomial<T>> for &Polynomial<T>
+where
+    T: Clone
+        + core::cmp::PartialEq
+        + Default
+        + Num
+        + core::ops::Add<Output = T>
+        + core::ops::Mul<Output = T>
+        + core::ops::AddAssign<<T as core::ops::Mul>::Output>
+        + core::ops::Neg<Output = T>,
+{
+    type Output = Polynomial<T>;
+
+    fn mul(self, other: Polynomial<T>) -> Polynomial<T> {
+        Polynomial::mul(self, &other)
+    }
+}
+
+impl<T> core::ops::Mul<&Polynomial<T>> for &Polynomial<T>
+where
+    T: Clone
+        + core::cmp::PartialEq
+        + Default
+        + Num
+        + core::ops::Add<Output = T>
+        + core::ops::Mul<Output = T>
+        + core::ops::AddAssign<<T as core::ops::Mul>::Output>
+        + core::ops::Neg<Output = T>,
+{
+    type Output = Polynomial<T>;
+
+    fn mul(self, other: &Polynomial<T>) -> Polynomial<T> {
+        Polynomial::mul(self, other)
+    }
+}
+
+impl<T> core::ops::Mul<T> for Polynomial<T>
+where
+    T: Clone
+        + core::cmp::PartialEq
+        + Default
+        + Num
+        + core::ops::Add<Output = T>
+        + core::ops::Mul<Output = T>
+        + core::ops::AddAssign<<T as core::ops::Mul>::Output>
+        + core::ops::Neg<Output = T>,
+{
+    type Output = Polynomial<T>;
+
+    fn mul(self, k: T) -> Polynomial<T> {
+        self.scale(k)
+    }
+}
+
+impl<T> core::ops::Mul<T> for &Polynomial<T>
+where
+    T: Clone
+        + core::cmp::PartialEq
+        + Default
+        + Num
+        + core::ops::Add<Output = T>
+        + core::ops::Mul<Output = T>
+        + core::ops::AddAssign<<T as core::ops::Mul>::Output>
+        + core::ops::Neg<Output = T>,
+{
+    type Output = Polynomial<T>;
+
+    fn mul(self, k: T) -> Polynomial<T> {
+        self.scale(k)
+    }
+}
+
+impl<T> core::ops::Div<T> for Polynomial<T>
+where
+    T: Clone
+        + core::cmp::PartialEq
+        + Default
+        + Num
+        + core::ops::Add<Output = T>
+        + core::ops::Mul<Output = T>
+        + core::ops::AddAssign<<T as core::ops::Mul>::Output>
+        + core::ops::Neg<Output = T>,
+{
+    type Output = Polynomial<T>;
+
+    fn div(self, k: T) -> Polynomial<T> {
+        &self / k
+    }
+}
+
+impl<T> core::ops::Div<T> for &Polynomial<T>
+where
+    T: Clone
+        + core::cmp::PartialEq
+        + Default
+        + Num
+        + core::ops::Add<Output = T>
+        + core::ops::Mul<Output = T>
+        + core::ops::AddAssign<<T as core::ops::Mul>::Output>
+        + core::ops::Neg<Output = T>,
+{
+    type Output = Polynomial<T>;
+
+    fn div(self, k: T) -> Polynomial<T> {
+        if k == T::default() {
+            panic!("polynomial division by a zero scalar");
+        }
+        Polynomial::new(
+            self.coefs_
+                .iter()
+                .map(|coef| coef.clone() / k.clone())
+                .collect(),
+        )
+    }
+}
+
+impl<T> core::ops::AddAssign<&Polynomial<T>> for Polynomial<T>
+where
+    T: Clone
+        + core::cmp::PartialEq
+        + Default
+        + Num
+        + core::ops::Add<Output = T>
+        + core::ops::Mul<Output = T>
+        + core::ops::AddAssign<<T as core::ops::Mul>::Output>
+        + core::ops::Neg<Output = T>,
+{
+    fn add_assign(&mut self, other: &Polynomial<T>) {
+        *self = Polynomial::add(self, other);
+    }
+}
+
+impl<T> core::ops::MulAssign<&Polynomial<T>> for Polynomial<T>
+where
+    T: Clone
+        + core::cmp::PartialEq
+        + Default
+        + Num
+        + core::ops::Add<Output = T>
+        + core::ops::Mul<Output = T>
+        + core::ops::AddAssign<<T as core::ops::Mul>::Output>
+        + core::ops::Neg<Output = T>,
+{
+    fn mul_assign(&mut self, other: &Polynomial<T>) {
+        *self = Polynomial::mul(self, other);
+    }
+}
+
+impl<T> core::iter::Sum for Polynomial<T>
+where
+    T: Clone
+        + core::cmp::PartialEq
+        + Default
+        + Num
+        + core::ops::Add<Output = T>
+        + core::ops::Mul<Output = T>
+        + core::ops::AddAssign<<T as core::ops::Mul>::Output>
+        + core::ops::Neg<Output = T>,
+{
+    fn sum<I: Iterator<Item = Polynomial<T>>>(iter: I) -> Self {
+        iter.fold(Polynomial::zero(), |acc, x| acc.add(&x))
+    }
+}
+
+impl<'a, T> core::iter::Sum<&'a Polynomial<T>> for Polynomial<T>
+where
+    T: Clone
+        + core::cmp::PartialEq
+        + Default
+        + Num
+        + core::ops::Add<Output = T>
+        + core::ops::Mul<Output = T>
+        + core::ops::AddAssign<<T as core::ops::Mul>::Output>
+        + core::ops::Neg<Output = T>,
+{
+    fn sum<I: Iterator<Item = &'a Polynomial<T>>>(iter: I) -> Self {
+        iter.fold(Polynomial::zero(), |acc, x| acc.add(x))
+    }
+}
+
+impl<T> core::iter::Product for Polynomial<T>
+where
+    T: Clone
+        + core::cmp::PartialEq
+        + Default
+        + Num
+        + core::ops::Add<Output = T>
+        + core::ops::Mul<Output = T>
+        + core::ops::AddAssign<<T as core::ops::Mul>::Output>
+        + core::ops::Neg<Output = T>,
+{
+    fn product<I: Iterator<Item = Polynomial<T>>>(iter: I) -> Self {
+        iter.fold(Polynomial::one(), |acc, x| acc.mul(&x))
+    }
+}
+
+impl<'a, T> core::iter::Product<&'a Polynomial<T>> for Polynomial<T>
+where
+    T: Clone
+        + core::cmp::PartialEq
+        + Default
+        + Num
+        + core::ops::Add<Output = T>
+        + core::ops::Mul<Output = T>
+        + core::ops::AddAssign<<T as core::ops::Mul>::Output>
+        + core::ops::Neg<Output = T>,
+{
+    fn product<I: Iterator<Item = &'a Polynomial<T>>>(iter: I) -> Self {
+        iter.fold(Polynomial::one(), |acc, x| acc.mul(x))
+    }
+}
+
+impl<T> Polynomial<T>
+where
+    T: core::fmt::Display + core::cmp::PartialEq + Default,
+{
+    // writes terms in descending degree order, skipping zero coefficients;
+    // signs and unit coefficients are read off the formatted coefficient so
+    // no arithmetic is needed on T
+    fn write_terms<W: Write>(&self, w: &mut W, var: &str, latex: bool) -> core::fmt::Result {
+        let zero = T::default();
+        let mut first = true;
+
+        for (pow, coef) in self.coefs_.iter().enumerate().rev() {
+            if *coef == zero {
+                continue;
+            }
+
+            let coef = coef.to_string();
+            // compound coefficients such as complex numbers carry their own
+            // signs and get parenthesized instead
+            let compound = coef.chars().skip(1).any(|c| c == '+' || c == '-');
+            let negative = !compound && coef.starts_with('-');
+            let abs = if negative { &coef[1..] } else { &coef[..] };
+            if first {
+                if negative {
+                    write!(w, "-")?;
+                }
+            } else if negative {
+                write!(w, " - ")?;
+            } else {
+                write!(w, " + ")?;
+            }
+            first = false;
+
+            if compound {
+                write!(w, "({})", abs)?;
+            } else if pow == 0 || abs != "1" {
+                write!(w, "{}", abs)?;
+            }
+            match pow {
+                0 => {}
+                1 => write!(w, "{}", var)?,
+                _ if latex => write!(w, "{}^{{{}}}", var, pow)?,
+                _ => write!(w, "{}^{}", var, pow)?,
+            }
+        }
+
+        if first {
+            write!(w, "{}", zero)?;
+        }
+        Ok(())
+    }
+
+    pub fn to_string_with_var(&self, var: &str) -> String {
+        let mut res = String::new();
+        self.write_terms(&mut res, var, false).unwrap();
+        res
+    }
+
+    pub fn to_latex(&self) -> String {
+        let mut latex = String::new();
+        self.write_terms(&mut latex, "x", true).unwrap();
+        latex
+    }
+}
+
+impl<T> core::fmt::Display for Polynomial<T>
+where
+    T: core::fmt::Display + core::cmp::PartialEq + Default,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        self.write_terms(f, "x", false)
+    }
+}
+
+impl<T> core::fmt::Debug for Polynomial<T>
+where
+    T: core::fmt::Display + core::cmp::PartialEq + Default,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "Poly: ")?;
+
+        let zero = T::default();
+        let mut first = true;
+        for (pow, coef) in self.coefs_.iter().enumerate() {
+            if *coef == zero {
+                continue;
+            }
+            if !first {
+                write!(f, " + ")?;
+            }
+            first = false;
+
+            match pow {
+                0 => write!(f, "({})", coef)?,
+                1 => write!(f, "({}x)", coef)?,
+                _ => write!(f, "({}x^{})", coef, pow)?,
+            }
+        }
+
+        // only the zero polynomial has no nonzero terms
+        if first {
+            write!(f, "({})", zero)?;
+        }
+        Ok(())
+    }
+}
+
+impl<T: core::cmp::PartialEq> PartialEq for Polynomial<T> {
+    fn eq(&self, other: &Self) -> bool {
+        if self.coefs_.len() != other.coefs_.len() {
+            return false;
+        }
+        for i in 0..self.coefs_.len() {
+            if self.coefs_[i] != other.coefs_[i] {
+                return false;
+            }
+        }
+        true
+    }
+}
+
+// structural order for canonicalizing collections, not an order on values:
+// polynomials compare by degree first (the zero polynomial lowest), then by
+// coefficients from the highest degree down
+impl<T: core::cmp::PartialOrd + Default> PartialOrd for Polynomial<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<cmp::Ordering> {
+        let degree = |p: &Self| {
+            if p.coefs_.iter().all(|coef| *coef == T::default()) {
+                None
+            } else {
+                Some(p.coefs_.len() - 1)
+            }
+        };
+
+        match degree(self).cmp(&degree(other)) {
+            cmp::Ordering::Equal => self
+                .coefs_
+                .iter()
+                .rev()
+                .partial_cmp(other.coefs_.iter().rev()),
+            ord => Some(ord),
+        }
+    }
+}
+
+// coefficient equality is total for Eq types, which together with Hash lets
+// polynomials be used as HashSet/HashMap keys
+impl<T: core::cmp::Eq> Eq for Polynomial<T> {}
+
+// coefficients are kept trimmed by new(), so equal polynomials hash equally
+impl<T: core::hash::Hash> core::hash::Hash for Polynomial<T> {
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        self.coefs_.hash(state);
+    }
+}
+
+// only the coefficient vector is serialized, deserialized coefficients go
+// through new() so the trimmed form is preserved
+#[cfg(feature = "serde")]
+impl<T: serde::Serialize> serde::Serialize for Polynomial<T> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.coefs_.serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, T> serde::Deserialize<'de> for Polynomial<T>
+where
+    T: serde::Deserialize<'de>
+        + Clone
+        + core::cmp::PartialEq
+        + Default
+        + Num
+        + core::ops::Add<Output = T>
+        + core::ops::Mul<Output = T>
+        + core::ops::AddAssign<<T as core::ops::Mul>::Output>
+        + core::ops::Neg<Output = T>,
+{
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Vec::<T>::deserialize(deserializer).map(Polynomial::new)
+    }
+}
+
+// iterates coefficients in ascending degree order
+impl<T> IntoIterator for Polynomial<T> {
+    type Item = T;
+    type IntoIter = alloc::vec::IntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.coefs_.into_iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a Polynomial<T> {
+    type Item = &'a T;
+    type IntoIter = core::slice::Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.coefs_.iter()
+    }
+}
+
+impl<T> From<Vec<T>> for Polynomial<T>
+where
+    T: Clone
+        + core::cmp::PartialEq
+        + Default
+        + Num
+        + core::ops::Add<Output = T>
+        + core::ops::Mul<Output = T>
+        + core::ops::AddAssign<<T as core::ops::Mul>::Output>
+        + core::ops::Neg<Output = T>,
+{
+    fn from(coefs: Vec<T>) -> Self {
+        Polynomial::new(coefs)
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct ParsePolynomialError {
+    token: String,
+}
+
+impl core::fmt::Display for ParsePolynomialError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "invalid polynomial coefficient `{}`", self.token)
+    }
+}
+
+impl core::error::Error for ParsePolynomialError {}
+
+// parses a comma-separated list of coefficients in ascending degree order,
+// an empty string is the zero polynomial
+impl<T> core::str::FromStr for Polynomial<T>
+where
+    T: core::str::FromStr
+        + Clone
+        + core::cmp::PartialEq
+        + Default
+        + Num
+        + core::ops::Add<Output = T>
+        + core::ops::Mul<Output = T>
+        + core::ops::AddAssign<<T as core::ops::Mul>::Output>
+        + core::ops::Neg<Output = T>,
+{
+    type Err = ParsePolynomialError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.trim().is_empty() {
+            return Ok(Polynomial::zero());
+        }
+
+        s.split(',')
+            .map(|token| {
+                let token = token.trim();
+                token.parse::<T>().map_err(|_| ParsePolynomialError {
+                    token: token.to_string(),
+                })
+            })
+            .collect::<Result<Vec<T>, _>>()
+            .map(Polynomial::new)
+    }
+}
+
+// collects coefficients in ascending degree order
+impl<T> FromIterator<T> for Polynomial<T>
+where
+    T: Clone
+        + core::cmp::PartialEq
+        + Default
+        + Num
+        + core::ops::Add<Output = T>
+        + core::ops::Mul<Output = T>
+        + core::ops::AddAssign<<T as core::ops::Mul>::Output>
+        + core::ops::Neg<Output = T>,
+{
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        Polynomial::new(iter.into_iter().collect())
+    }
+}
+
+// panics on out-of-range indices like Vec does, use coefficient() to get
+// zero for degrees past the stored coefficients
+impl<T> core::ops::Index<usize> for Polynomial<T> {
+    type Output = T;
+
+    fn index(&self, i: usize) -> &T {
+        &self.coefs_[i]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+
+    use super::*;
+    use std::{format, println};
+
+    #[test]
+    fn it_work() {
+        let poly = Polynomial::new(vec![0.0, 1.0, 2.0, 3.0]);
+        println!("{:?}", poly);
+        let poly1 = Polynomial::new(vec![0.0, 1.0, 2.0, 3.0, 0.0, 0.0]);
+        println!("{:?}", poly1);
+        let poly1 = Polynomial::new(vec![0.0, 1.0, 2.0, 3.0, 0.0]);
+        println!("{:?}", poly1);
+        let poly1 = Polynomial::new(vec![0.0, 0.0, 0.0]);
+        println!("{:?}", poly1);
+        let poly1 = Polynomial::new(vec![1.0]);
+        println!("{:?}", poly1);
+        let poly1 = Polynomial::new(vec![0.0]);
+        println!("{:?}", poly1);
+    }
+
+    #[test]
+    fn constructors() {
+        assert_eq!(Polynomial::<i32>::one().eval_at(5), 1);
+        assert_eq!(Polynomial::<i32>::one(), Polynomial::new(vec![1]));
+        assert!(Polynomial::<f64>::zero().is_zero());
+        assert_eq!(Polynomial::<f64>::zero(), Polynomial::new(vec![0.0, 0.0]));
+        assert_eq!(Polynomial::constant(3.5), Polynomial::new(vec![3.5]));
+        assert!(Polynomial::constant(0).is_zero());
+    }
+
+    #[test]
+    fn monomial() {
+        assert_eq!(Polynomial::monomial(3, 2), Polynomial::new(vec![0, 0, 3]));
+        assert_eq!(Polynomial::monomial(0, 5), Polynomial::new(vec![0]));
+        assert_eq!(Polynomial::monomial(1.5, 0), Polynomial::new(vec![1.5]));
+    }
+
+    #[test]
+    fn from_roots() {
+        let poly = Polynomial::from_roots(&[1.0, 2.0]);
+        assert_eq!(poly, Polynomial::new(vec![2.0, -3.0, 1.0]));
+
+        let poly = Polynomial::from_roots(&[1, 2, 3]);
+        assert_eq!(poly, Polynomial::new(vec![-6, 11, -6, 1]));
+        for r in [1, 2, 3] {
+            assert_eq!(poly.eval_at(r), 0);
+        }
+
+        assert_eq!(Polynomial::<i32>::from_roots(&[]), Polynomial::one());
+    }
+
+    #[test]
+    fn is_zero() {
+        assert!(Polynomial::new(vec![0.0]).is_zero());
+        assert!(Polynomial::new(vec![0, 0, 0]).is_zero());
+        assert!(!Polynomial::new(vec![3.0]).is_zero());
+        assert!(!Polynomial::new(vec![0, 1]).is_zero());
+    }
+
+    #[test]
+    fn degree() {
+        assert_eq!(Polynomial::new(vec![0]).degree(), None);
+        assert_eq!(Polynomial::new(vec![0.0, 0.0]).degree(), None);
+        assert_eq!(Polynomial::new(vec![4]).degree(), Some(0));
+        assert_eq!(Polynomial::new(vec![1, 0, 0, 2]).degree(), Some(3));
+        assert_eq!(Polynomial::new(vec![1, 0, 0, 2, 0]).degree(), Some(3));
+    }
+
+    #[test]
+    fn coefficients() {
+        let poly = Polynomial::new(vec![1, 0, 3, 0]);
+        assert_eq!(poly.coefficient(0), 1);
+        assert_eq!(poly.coefficient(2), 3);
+        assert_eq!(poly.coefficient(3), 0);
+        assert_eq!(poly.coefficient(100), 0);
+        assert_eq!(poly.coefficients(), &[1, 0, 3]);
+        assert_eq!(poly.coefficients().len(), poly.degree().unwrap() + 1);
+    }
+
+    #[test]
+    fn set_coefficient() {
+        let mut poly = Polynomial::new(vec![1, 2, 3]);
+        poly.set_coefficient(1, 5);
+        assert_eq!(poly, Polynomial::new(vec![1, 5, 3]));
+        poly.set_coefficient(4, 1);
+        assert_eq!(poly, Polynomial::new(vec![1, 5, 3, 0, 1]));
+        poly.set_coefficient(4, 0);
+        assert_eq!(poly, Polynomial::new(vec![1, 5, 3]));
+    }
+
+    #[test]
+    fn clone() {
+        let mut poly = Polynomial::new(vec![1.0, 2.0, 3.0]);
+        let cloned = poly.clone();
+        assert_eq!(poly, cloned);
+
+        poly.set_coefficient(0, 7.0);
+        assert_eq!(poly, Polynomial::new(vec![7.0, 2.0, 3.0]));
+        assert_eq!(cloned, Polynomial::new(vec![1.0, 2.0, 3.0]));
+    }
+
+    #[test]
+    fn hash() {
+        use std::collections::HashSet;
+
+        let mut set = HashSet::new();
+        set.insert(Polynomial::new(vec![1, 2, 3]));
+        set.insert(Polynomial::new(vec![1, 2, 3, 0, 0]));
+        assert_eq!(set.len(), 1);
+
+        set.insert(Polynomial::new(vec![1, 2]));
+        assert_eq!(set.len(), 2);
+        assert!(set.contains(&Polynomial::new(vec![1, 2, 0])));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_round_trip() {
+        let poly = Polynomial::interpolate_from(vec![(1.0, 325.0), (3.0, 2383.0), (5.0, 6609.0)]);
+        let json = serde_json::to_string(&poly).unwrap();
+        assert_eq!(json, "[109.0,-55.0,271.0]");
+        let back: Polynomial<f64> = serde_json::from_str(&json).unwrap();
+        assert_eq!(back, poly);
+
+        let back: Polynomial<f64> = serde_json::from_str("[1.0,2.0,0.0,0.0]").unwrap();
+        assert_eq!(back, Polynomial::new(vec![1.0, 2.0]));
+    }
+
+    #[test]
+    fn into_iter() {
+        let poly = Polynomial::new(vec![3, 0, 2, 0, 0]);
+        let borrowed: Vec<i32> = (&poly).into_iter().copied().collect();
+        assert_eq!(borrowed, vec![3, 0, 2]);
+        for (pow, coef) in (&poly).into_iter().enumerate() {
+            assert_eq!(*coef, poly.coefficient(pow));
+        }
+
+        let owned: Vec<i32> = poly.into_iter().collect();
+        assert_eq!(owned, vec![3, 0, 2]);
+    }
+
+    #[test]
+    fn from_vec() {
+        let poly: Polynomial<i32> = vec![1, 2, 3].into();
+        assert_eq!(poly, Polynomial::new(vec![1, 2, 3]));
+
+        fn degree_of(poly: impl Into<Polynomial<f64>>) -> Option<usize> {
+            poly.into().degree()
+        }
+        assert_eq!(degree_of(vec![1.0, 2.0, 0.0]), Some(1));
+    }
+
+    #[test]
+    fn from_iter() {
+        let poly: Polynomial<f64> = (0..5).map(|i| i as f64).collect();
+        assert_eq!(poly, Polynomial::new(vec![0.0, 1.0, 2.0, 3.0, 4.0]));
+
+        let poly: Polynomial<i32> = vec![1, 2, 0, 0].into_iter().collect();
+        assert_eq!(poly, Polynomial::new(vec![1, 2]));
+
+        let poly: Polynomial<i32> = std::iter::empty().collect();
+        assert_eq!(poly, Polynomial::new(vec![0]));
+    }
+
+    #[test]
+    fn map_keys() {
+        use std::collections::HashMap;
+
+        let mut names = HashMap::new();
+        names.insert(Polynomial::new(vec![-1, 0, 1]), "x^2 - 1");
+        names.insert(Polynomial::new(vec![0, 1]), "x");
+
+        assert_eq!(
+            names.get(&Polynomial::from_roots(&[1, -1])),
+            Some(&"x^2 - 1")
+        );
+        assert_eq!(names.get(&Polynomial::new(vec![0, 1, 0])), Some(&"x"));
+        assert_eq!(names.get(&Polynomial::new(vec![1])), None);
+    }
+
+    #[test]
+    fn partial_ord() {
+        let mut polys = vec![
+            Polynomial::new(vec![0, 0, 1]),
+            Polynomial::new(vec![5]),
+            Polynomial::new(vec![3, 1]),
+            Polynomial::new(vec![0]),
+            Polynomial::new(vec![-7, 0, 1]),
+            Polynomial::new(vec![-2]),
+            Polynomial::new(vec![0, 2]),
+        ];
+        polys.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        assert_eq!(
+            polys,
+            vec![
+                Polynomial::new(vec![0]),
+                Polynomial::new(vec![-2]),
+                Polynomial::new(vec![5]),
+                Polynomial::new(vec![3, 1]),
+                Polynomial::new(vec![0, 2]),
+                Polynomial::new(vec![-7, 0, 1]),
+                Polynomial::new(vec![0, 0, 1]),
+            ]
+        );
+
+        assert!(Polynomial::new(vec![1.0, 2.0]) < Polynomial::new(vec![0.0, 0.0, 1.0]));
+        assert_eq!(
+            Polynomial::new(vec![1, 2]).partial_cmp(&Polynomial::new(vec![1, 2])),
+            Some(cmp::Ordering::Equal)
+        );
+        assert_eq!(
+            Polynomial::new(vec![f64::NAN, 1.0]).partial_cmp(&Polynomial::new(vec![0.0, 1.0])),
+            None
+        );
+    }
+
+    #[test]
+    fn index() {
+        let poly = Polynomial::new(vec![4, 5, 6]);
+        assert_eq!(poly[0], 4);
+        assert_eq!(poly[1], 5);
+        assert_eq!(poly[2], 6);
+    }
+
+    #[test]
+    #[should_panic]
+    fn index_out_of_range() {
+        let poly = Polynomial::new(vec![4, 5, 6, 0]);
+        let _ = poly[3];
+    }
+
+    #[test]
+    fn leading_coefficient() {
+        let poly = Polynomial::new(vec![7, 0, -1, 4]);
+        assert_eq!(poly.leading_coefficient(), 4);
+        assert_eq!(poly.constant_term(), 7);
+
+        let poly = Polynomial::new(vec![0.0, 0.0]);
+        assert_eq!(poly.leading_coefficient(), 0.0);
+        assert_eq!(poly.constant_term(), 0.0);
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(Polynomial::new(vec![1, 0, 3]).to_string(), "3x^2 + 1");
+        assert_eq!(Polynomial::new(vec![0]).to_string(), "0");
+        assert_eq!(Polynomial::new(vec![0.0, 0.0]).to_string(), "0");
+        assert_eq!(
+            Polynomial::new(vec![-1, -1, 0, -2]).to_string(),
+            "-2x^3 - x - 1"
+        );
+        assert_eq!(Polynomial::new(vec![0.5, -1.0]).to_string(), "-x + 0.5");
+        assert_eq!(Polynomial::new(vec![0, 1]).to_string(), "x");
+        assert_eq!(Polynomial::new(vec![-4]).to_string(), "-4");
+    }
+
+    #[test]
+    fn to_string_with_var() {
+        let poly = Polynomial::new(vec![2, -1, 0, 1]);
+        assert_eq!(poly.to_string_with_var("x"), poly.to_string());
+        assert_eq!(poly.to_string_with_var("x"), "x^3 - x + 2");
+        assert_eq!(poly.to_string_with_var("t"), "t^3 - t + 2");
+        assert_eq!(
+            poly.to_string_with_var("\\lambda"),
+            "\\lambda^3 - \\lambda + 2"
+        );
+    }
+
+    #[test]
+    fn to_latex() {
+        assert_eq!(Polynomial::new(vec![1, 1, 3]).to_latex(), "3x^{2} + x + 1");
+        assert_eq!(
+            Polynomial::new(vec![0, -2, 0, 0, 0, 0, 0, 0, 0, 0, 1]).to_latex(),
+            "x^{10} - 2x"
+        );
+        assert_eq!(
+            Polynomial::new(vec![0.5, 0.0, -1.0]).to_latex(),
+            "-x^{2} + 0.5"
+        );
+        assert_eq!(Polynomial::new(vec![0]).to_latex(), "0");
+    }
+
+    #[test]
+    fn from_str() {
+        let poly: Polynomial<i32> = "1, 2, 3".parse().unwrap();
+        assert_eq!(poly, Polynomial::new(vec![1, 2, 3]));
+
+        let poly: Polynomial<f64> = " 0.5,-1 ,0,  0 ".parse().unwrap();
+        assert_eq!(poly, Polynomial::new(vec![0.5, -1.0]));
+
+        let poly: Polynomial<i32> = "  ".parse().unwrap();
+        assert!(poly.is_zero());
+
+        let err = "1, x, 3".parse::<Polynomial<i32>>().unwrap_err();
+        assert_eq!(err.to_string(), "invalid polynomial coefficient `x`");
+        assert!("1,,3".parse::<Polynomial<i32>>().is_err());
+
+        let poly = Polynomial::new(vec![4, 0, -7, 2]);
+        let csv: Vec<String> = poly.coefficients().iter().map(|c| c.to_string()).collect();
+        assert_eq!(csv.join(", ").parse::<Polynomial<i32>>().unwrap(), poly);
+    }
+
+    #[test]
+    fn new_empty() {
+        let poly: Polynomial<i32> = Polynomial::new(vec![]);
+        assert!(poly.is_zero());
+        assert_eq!(poly, Polynomial::new(vec![0]));
+        assert_eq!(poly.eval_at(7), 0);
+        assert_eq!(
+            poly.mul(&Polynomial::new(vec![1, 2])),
+            Polynomial::new(vec![0])
+        );
+        assert_eq!(format!("{:?}", poly), "Poly: (0)");
+    }
+
+    #[test]
+    fn sum() {
+        let poly1 = Polynomial::new(vec![0.0, 1.0, 2.0]);
+        let poly2 = Polynomial::new(vec![1.0, 2.0, 3.0]);
+        let poly3 = poly1.add(&poly2);
+        assert_eq!(poly3, Polynomial::new(vec![1.0, 3.0, 5.0]));
+
+        let poly1 = Polynomial::new(vec![1.0]);
+        let poly2 = Polynomial::new(vec![0.0, 1.0, 3.0]);
+        let poly3 = poly1.add(&poly2);
+        assert_eq!(poly3, Polynomial::new(vec![1.0, 1.0, 3.0]));
+        let poly3 = poly2.add(&poly1);
+        assert_eq!(poly3, Polynomial::new(vec![1.0, 1.0, 3.0]));
+
+        let poly1 = Polynomial::new(vec![0]);
+        let poly2 = Polynomial::new(vec![0, 1]);
+        let poly3 = poly1.add(&poly2);
+        assert_eq!(poly3, Polynomial::new(vec![0, 1]));
+    }
+
+    #[test]
+    fn add_op() {
+        let poly1 = Polynomial::new(vec![0.0, 1.0, 2.0]);
+        let poly2 = Polynomial::new(vec![1.0, 2.0, 3.0, 4.0]);
+        let expected = poly1.add(&poly2);
+        assert_eq!(&poly1 + &poly2, expected);
+        assert_eq!(&poly2 + &poly1, expected);
+
+        let poly1 = Polynomial::new(vec![1, 2, 3]);
+        let poly2 = Polynomial::new(vec![-1, -2, -3]);
+        let expected = poly1.add(&poly2);
+        assert_eq!(&poly1 + poly2.neg(), poly1.add(&poly2.neg()));
+        assert_eq!(poly1.neg() + &poly2, poly1.neg().add(&poly2));
+        assert_eq!(poly1 + poly2, expected);
+        assert_eq!(expected, Polynomial::new(vec![0]));
+    }
+
+    #[test]
+    fn assign_ops() {
+        let poly1 = Polynomial::new(vec![1, 2, 3]);
+        let poly2 = Polynomial::new(vec![0, -2, -3, 4]);
+
+        let mut acc = poly1.clone();
+        acc += &poly2;
+        assert_eq!(acc, poly1.add(&poly2));
+
+        let mut acc = poly1.clone();
+        acc *= &poly2;
+        assert_eq!(acc, poly1.mul(&poly2));
+
+        let mut acc = Polynomial::one();
+        for r in [1, 2, 3] {
+            acc *= &Polynomial::new(vec![-r, 1]);
+        }
+        assert_eq!(acc, Polynomial::from_roots(&[1, 2, 3]));
+    }
+
+    #[test]
+    fn sum_iter() {
+        let polys = vec![
+            Polynomial::new(vec![1, 2, 3]),
+            Polynomial::new(vec![0, -2]),
+            Polynomial::new(vec![4, 0, 0, 1]),
+        ];
+        let expected = polys[0].add(&polys[1]).add(&polys[2]);
+        assert_eq!(polys.iter().sum::<Polynomial<i32>>(), expected);
+        assert_eq!(polys.into_iter().sum::<Polynomial<i32>>(), expected);
+
+        let empty: Vec<Polynomial<i32>> = vec![];
+        assert_eq!(
+            empty.into_iter().sum::<Polynomial<i32>>(),
+            Polynomial::zero()
+        );
+    }
+
+    #[test]
+    fn product_iter() {
+        let factors = vec![Polynomial::new(vec![-1, 1]), Polynomial::new(vec![-2, 1])];
+        assert_eq!(
+            factors.iter().product::<Polynomial<i32>>(),
+            Polynomial::from_roots(&[1, 2])
+        );
+        assert_eq!(
+            factors.into_iter().product::<Polynomial<i32>>(),
+            Polynomial::from_roots(&[1, 2])
+        );
+
+        let empty: Vec<Polynomial<i32>> = vec![];
+        assert_eq!(
+            empty.into_iter().product::<Polynomial<i32>>(),
+            Polynomial::one()
+        );
+    }
+
+    #[test]
+    fn sub() {
+        let poly1 = Polynomial::new(vec![1, 2, 3]);
+        let poly2 = Polynomial::new(vec![1, 2, 3]);
+        let poly3 = poly1.sub(&poly2);
+        assert_eq!(poly3, Polynomial::new(vec![0]));
+
+        let poly1 = Polynomial::new(vec![1.0]);
+        let poly2 = Polynomial::new(vec![0.0, 1.0, 3.0]);
+        let poly3 = poly1.sub(&poly2);
+        assert_eq!(poly3, Polynomial::new(vec![1.0, -1.0, -3.0]));
+        let poly3 = poly2.sub(&poly1);
+        assert_eq!(poly3, Polynomial::new(vec![-1.0, 1.0, 3.0]));
+
+        let poly1 = Polynomial::new(vec![5, 2, 3]);
+        let poly2 = Polynomial::new(vec![1, 2, 3]);
+        let poly3 = poly1.sub(&poly2);
+        assert_eq!(poly3, Polynomial::new(vec![4]));
+    }
+
+    #[test]
+    fn neg() {
+        let poly = Polynomial::new(vec![1, -2, 3]);
+        assert_eq!(poly.neg(), Polynomial::new(vec![-1, 2, -3]));
+        assert_eq!(poly.neg().neg(), poly);
+
+        let poly = Polynomial::new(vec![0.5, 0.0, -1.5]);
+        assert_eq!(poly.neg(), Polynomial::new(vec![-0.5, 0.0, 1.5]));
+        assert_eq!(poly.neg().neg(), poly);
+
+        let poly = Polynomial::new(vec![0]);
+        assert_eq!(poly.neg(), Polynomial::new(vec![0]));
+    }
+
+    #[test]
+    fn mul() {
+        let poly1 = Polynomial::new(vec![1.0, 2.0, 3.0]);
+        let poly2 = Polynomial::new(vec![1.0, 2.0, 3.0]);
+        let poly3 = poly1.mul(&poly2);
+        assert_eq!(poly3, Polynomial::new(vec![1.0, 4.0, 10.0, 12.0, 9.0]));
+
+        let poly1 = Polynomial::new(vec![1, 2, 3]);
+        let poly2 = Polynomial::new(vec![0]);
+        let poly3 = poly1.mul(&poly2);
+        assert_eq!(poly3, Polynomial::new(vec![0]));
+
+        let poly1 = Polynomial::new(vec![1, 2, 3, 4]);
+        let poly2 = Polynomial::new(vec![0, 1, 2, 3]);
+        let poly3 = poly1.mul(&poly2);
+        assert_eq!(poly3, Polynomial::new(vec![0, 1, 4, 10, 16, 17, 12]));
+
+        let poly1 = Polynomial::new(vec![1, 2, 3]);
+        let poly2 = Polynomial::new(vec![1, 2, 3, 4, 5]);
+        let poly3 = poly1.mul(&poly2);
+        assert_eq!(poly3, Polynomial::new(vec![1, 4, 10, 16, 22, 22, 15]));
+    }
+
+    #[test]
+    fn mul_empty_and_zero() {
+        let empty: Polynomial<i32> = Polynomial { coefs_: vec![] };
+        let poly = Polynomial::new(vec![1, 2, 3]);
+        assert_eq!(empty.mul(&poly), Polynomial::new(vec![0]));
+        assert_eq!(poly.mul(&empty), Polynomial::new(vec![0]));
+        assert_eq!(empty.mul(&empty), Polynomial::new(vec![0]));
+        assert_eq!(empty.mul_karatsuba(&poly), Polynomial::new(vec![0]));
+        assert_eq!(poly.mul(&Polynomial::zero()), Polynomial::new(vec![0]));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn mul_fft_empty_and_zero() {
+        let empty: Polynomial<f64> = Polynomial { coefs_: vec![] };
+        let poly = Polynomial::new(vec![1.0, 2.0]);
+        assert_eq!(empty.mul_fft(&poly), Polynomial::new(vec![0.0]));
+        assert_eq!(
+            poly.mul_fft(&Polynomial::zero()),
+            Polynomial::new(vec![0.0])
+        );
+    }
+
+    #[test]
+    fn mul_karatsuba() {
+        // small linear congruential generator, enough for test inputs
+        let mut seed: u64 = 42;
+        let mut random_poly = |len: usize| -> Polynomial<i64> {
+            let coefs = (0..len)
+                .map(|_| {
+                    seed = seed
+                        .wrapping_mul(6364136223846793005)
+                        .wrapping_add(1442695040888963407);
+                    (seed >> 33) as i64 % 201 - 100
+                })
+                .collect();
+            Polynomial::new(coefs)
+        };
+
+        for (n, m) in [
+            (1, 1),
+            (5, 40),
+            (33, 33),
+            (34, 90),
+            (64, 100),
+            (150, 150),
+            (200, 37),
+        ] {
+            let poly1 = random_poly(n);
+            let poly2 = random_poly(m);
+            let expected = Polynomial::new(Polynomial::schoolbook_mul(
+                poly1.coefficients(),
+                poly2.coefficients(),
+            ));
+            assert_eq!(poly1.mul_karatsuba(&poly2), expected);
+            assert_eq!(poly2.mul_karatsuba(&poly1), expected);
+            assert_eq!(poly1.mul(&poly2), expected);
+        }
+
+        let poly = Polynomial::new(vec![1i64, 1]);
+        assert_eq!(poly.pow(40).coefficient(20), 137846528820);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn mul_fft() {
+        let poly1 = Polynomial::new(vec![1.0, 2.0, 3.0]);
+        let poly2 = Polynomial::new(vec![1.0, 2.0, 3.0]);
+        let product = poly1.mul_fft(&poly2);
+        for (c, e) in product
+            .coefficients()
+            .iter()
+            .zip([1.0, 4.0, 10.0, 12.0, 9.0])
+        {
+            assert!((c - e).abs() < 1e-9);
+        }
+
+        let poly1: Polynomial<f64> = (0..300).map(|i| ((i * 7) % 13) as f64 - 6.0).collect();
+        let poly2: Polynomial<f64> = (0..257).map(|i| ((i * 5) % 11) as f64 * 0.5).collect();
+        let expected = poly1.mul(&poly2);
+        let product = poly1.mul_fft(&poly2);
+        assert_eq!(product.coefficients().len(), expected.coefficients().len());
+        for (c, e) in product.coefficients().iter().zip(expected.coefficients()) {
+            assert!((c - e).abs() < 1e-6);
+        }
+    }
+
+    #[test]
+    fn mul_op() {
+        let poly1 = Polynomial::new(vec![1, 2, 3, 4]);
+        let poly2 = Polynomial::new(vec![0, 1, 2, 3]);
+        let expected = poly1.mul(&poly2);
+        assert_eq!(&poly1 * &poly2, expected);
+        assert_eq!(&poly2 * &poly1, expected);
+        assert_eq!(&poly1 * Polynomial::new(vec![0, 1, 2, 3]), expected);
+        assert_eq!(Polynomial::new(vec![1, 2, 3, 4]) * &poly2, expected);
+        assert_eq!(poly1 * poly2, expected);
+
+        let poly1 = Polynomial::new(vec![1.0, 2.0, 3.0]);
+        let zero = Polynomial::new(vec![0.0, 0.0]);
+        assert_eq!(&poly1 * &zero, Polynomial::new(vec![0.0]));
+        assert_eq!(&zero * &poly1, Polynomial::new(vec![0.0]));
+        assert_eq!(&zero * &zero, Polynomial::new(vec![0.0]));
+    }
+
+    #[test]
+    fn shift_up() {
+        let poly = Polynomial::new(vec![1, 2, 3]);
+        assert_eq!(poly.shift_up(2), Polynomial::new(vec![0, 0, 1, 2, 3]));
+        assert_eq!(poly.shift_up(0), poly);
+        assert_eq!(poly.shift_up(3), poly.mul(&Polynomial::monomial(1, 3)));
+        assert_eq!(
+            Polynomial::new(vec![0]).shift_up(4),
+            Polynomial::new(vec![0])
+        );
+    }
+
+    #[test]
+    fn shift_down() {
+        let poly = Polynomial::new(vec![0, 0, 1, 2, 3]);
+        assert_eq!(poly.shift_down(2), Some(Polynomial::new(vec![1, 2, 3])));
+        assert_eq!(poly.shift_down(0), Some(poly.clone()));
+        assert_eq!(poly.shift_down(3), None);
+        assert_eq!(poly.shift_down(10), None);
+        assert_eq!(
+            Polynomial::new(vec![1, 2, 3]).shift_up(4).shift_down(4),
+            Some(Polynomial::new(vec![1, 2, 3]))
+        );
+        assert_eq!(
+            Polynomial::new(vec![0]).shift_down(3),
+            Some(Polynomial::new(vec![0]))
+        );
+    }
+
+    #[test]
+    fn add_scalar() {
+        let poly = Polynomial::new(vec![1, 2, 3]);
+        assert_eq!(poly.add_scalar(4), Polynomial::new(vec![5, 2, 3]));
+        assert_eq!(poly.add_scalar(4), poly.add(&Polynomial::constant(4)));
+
+        let cancelled = poly.add_scalar(-1);
+        assert_eq!(cancelled, Polynomial::new(vec![0, 2, 3]));
+        assert_eq!(cancelled.degree(), Some(2));
+
+        assert!(Polynomial::new(vec![2.5]).add_scalar(-2.5).is_zero());
+        assert_eq!(
+            Polynomial::new(vec![0.0]).add_scalar(1.5),
+            Polynomial::new(vec![1.5])
+        );
+    }
+
+    #[test]
+    fn reverse() {
+        let poly = Polynomial::new(vec![1, 2, 3]);
+        assert_eq!(poly.reverse(), Polynomial::new(vec![3, 2, 1]));
+        assert_eq!(poly.reverse().reverse(), poly);
+
+        let poly = Polynomial::new(vec![0, 0, 1, 2]);
+        assert_eq!(poly.reverse(), Polynomial::new(vec![2, 1]));
+        assert_eq!(poly.reverse().reverse(), Polynomial::new(vec![1, 2]));
+
+        assert!(Polynomial::new(vec![0]).reverse().is_zero());
+    }
+
+    #[test]
+    fn checked_eval_at() {
+        let poly = Polynomial::new(vec![1i64, 2, 3, 4, 5, 6, 7]);
+        assert_eq!(poly.checked_eval_at(29), Some(poly.eval_at(29)));
+        assert_eq!(poly.checked_eval_at(-3), Some(poly.eval_at(-3)));
+        assert_eq!(poly.checked_eval_at(2000), None);
+
+        let poly = Polynomial::new(vec![0i32, 0, 1]);
+        assert_eq!(poly.checked_eval_at(46340), Some(2147395600));
+        assert_eq!(poly.checked_eval_at(46341), None);
+    }
+
+    #[test]
+    fn checked_mul() {
+        let a = Polynomial::new(vec![1i64, 2, 3]);
+        let b = Polynomial::new(vec![-4i64, 5]);
+        assert_eq!(a.checked_mul(&b), Some(a.mul(&b)));
+        assert_eq!(a.checked_mul(&Polynomial::zero()), Some(Polynomial::zero()));
+
+        let big = Polynomial::new(vec![1i64, i64::MAX / 2]);
+        assert_eq!(
+            big.checked_mul(&Polynomial::new(vec![0, 2])),
+            Some(Polynomial::new(vec![0, 2, i64::MAX - 1]))
+        );
+        assert_eq!(big.checked_mul(&big), None);
+    }
+
+    #[test]
+    fn terms() {
+        let poly = Polynomial::new(vec![1, 0, 0, 3]);
+        assert_eq!(poly.num_terms(), 2);
+        assert_eq!(poly.terms().collect::<Vec<_>>(), vec![(0, 1), (3, 3)]);
+
+        let poly = Polynomial::new(vec![0, -2, 0, 0, 5, 0]);
+        assert_eq!(poly.num_terms(), 2);
+        assert_eq!(poly.terms().collect::<Vec<_>>(), vec![(1, -2), (4, 5)]);
+
+        let zero = Polynomial::<i32>::zero();
+        assert_eq!(zero.num_terms(), 0);
+        assert_eq!(zero.terms().next(), None);
+    }
+
+    #[test]
+    fn eval_at_matrix() {
+        let m = vec![vec![1, 2], vec![3, 4]];
+        let poly = Polynomial::new(vec![2, -3, 1]);
+        assert_eq!(poly.eval_at_matrix(&m), vec![vec![6, 4], vec![6, 12]]);
+
+        // cayley-hamilton, the characteristic polynomial annihilates m
+        let char_poly = Polynomial::new(vec![-2, -5, 1]);
+        assert_eq!(char_poly.eval_at_matrix(&m), vec![vec![0, 0], vec![0, 0]]);
+
+        assert_eq!(
+            Polynomial::new(vec![7]).eval_at_matrix(&m),
+            vec![vec![7, 0], vec![0, 7]]
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn eval_at_non_square_matrix() {
+        Polynomial::new(vec![1, 1]).eval_at_matrix(&[vec![1, 2]]);
+    }
+
+    #[test]
+    fn eval_generic() {
+        use num::Complex;
+
+        // 3x^2 - 2x + 1 at 1 + 2i
+        let poly = Polynomial::new(vec![1, -2, 3]);
+        assert_eq!(poly.eval_generic(Complex::new(1, 2)), Complex::new(-10, 8));
+        assert_eq!(
+            poly.cast::<f64>().eval_generic(Complex::new(1.0, 2.0)),
+            Complex::new(-10.0, 8.0)
+        );
+        assert_eq!(poly.eval_generic(0.5f64), 0.75);
+        assert_eq!(poly.eval_generic(4i64), poly.eval_at(4) as i64);
+    }
+
+    #[test]
+    fn eval_dual() {
+        let polys = [
+            Polynomial::new(vec![1, -2, 3]),
+            Polynomial::new(vec![0, 0, 0, 0, 1]),
+            Polynomial::new(vec![-7, 4, 0, -1, 2]),
+            Polynomial::new(vec![5]),
+        ];
+        for poly in &polys {
+            for x in [-3, 0, 1, 4] {
+                assert_eq!(poly.eval_dual(x), poly.eval_with_derivative(x));
+            }
+        }
+
+        let poly = Polynomial::new(vec![0.5, 0.0, -1.5]);
+        assert_eq!(poly.eval_dual(2.0), (-5.5, -6.0));
+
+        let d = Dual::new(3, 1) * Dual::new(3, 1) + Dual::from(2);
+        assert_eq!(d, Dual::new(11, 6));
+    }
+
+    #[test]
+    fn debug() {
+        let poly = Polynomial::new(vec![0, 1, 0, 3]);
+        assert_eq!(format!("{:?}", poly), "Poly: (1x) + (3x^3)");
+
+        let poly = Polynomial::new(vec![-2, 0, 1]);
+        assert_eq!(format!("{:?}", poly), "Poly: (-2) + (1x^2)");
+
+        let poly = Polynomial::new(vec![0.5, -1.0]);
+        assert_eq!(format!("{:?}", poly), "Poly: (0.5) + (-1x)");
+        assert_eq!(format!("{:?}", Polynomial::<i32>::zero()), "Poly: (0)");
+    }
+
+    #[test]
+    fn format_without_arithmetic() {
+        // only display, equality and a default zero, no arithmetic
+        #[derive(Clone, PartialEq)]
+        struct Sym(&'static str);
+
+        impl Default for Sym {
+            fn default() -> Self {
+                Sym("0")
+            }
+        }
+
+        impl core::fmt::Display for Sym {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                write!(f, "{}", self.0)
+            }
+        }
+
+        let poly = Polynomial {
+            coefs_: vec![Sym("c"), Sym("0"), Sym("-b"), Sym("a")],
+        };
+        assert_eq!(poly.to_string(), "ax^3 - bx^2 + c");
+        assert_eq!(poly.to_latex(), "ax^{3} - bx^{2} + c");
+        assert_eq!(format!("{:?}", poly), "Poly: (c) + (-bx^2) + (ax^3)");
+
+        let poly = Polynomial {
+            coefs_: vec![Sym("0"), Sym("1")],
+        };
+        assert_eq!(poly.to_string_with_var("t"), "t");
+
+        let poly = Polynomial::new(vec![num::Complex::new(-1, 2), num::Complex::new(3, 0)]);
+        assert_eq!(poly.to_string(), "(3+0i)x + (-1+2i)");
+    }
+
+    #[test]
+    fn mod_coefficients() {
+        let poly = Polynomial::new(vec![7i64, -3, 10]);
+        let reduced = poly.mod_coefficients(5);
+        assert_eq!(reduced, Polynomial::new(vec![2, 2, 0]));
+        assert_eq!(reduced.coefficients(), &[2, 2]);
+        assert_eq!(reduced.degree(), Some(1));
+
+        let poly = Polynomial::new(vec![-1i64, -13, 6]);
+        assert_eq!(poly.mod_coefficients(3), Polynomial::new(vec![2, 2]));
+        assert!(Polynomial::new(vec![9i64, -6])
+            .mod_coefficients(3)
+            .is_zero());
+    }
+
+    #[test]
+    #[should_panic]
+    fn mod_coefficients_by_zero() {
+        Polynomial::new(vec![1i64]).mod_coefficients(0);
+    }
+
+    #[test]
+    fn mul_mod_xn() {
+        let a = Polynomial::new(vec![1, 2, 3, 4]);
+        let b = Polynomial::new(vec![5, -1, 0, 2, 7]);
+        let full = a.mul(&b);
+        for n in 0..10 {
+            let low = full
+                .coefficients()
+                .iter()
+                .take(n)
+                .cloned()
+                .collect::<Vec<_>>();
+            assert_eq!(a.mul_mod_xn(&b, n), Polynomial::new(low));
+        }
+        assert_eq!(a.mul_mod_xn(&b, 3), Polynomial::new(vec![5, 9, 13]));
+        assert!(a.mul_mod_xn(&Polynomial::zero(), 4).is_zero());
+    }
+
+    #[test]
+    fn truncate() {
+        let poly = Polynomial::new(vec![1, 2, 3, 4, 5]);
+        assert_eq!(poly.truncate(2), Polynomial::new(vec![1, 2, 3]));
+        assert_eq!(poly.truncate(0), Polynomial::new(vec![1]));
+        assert_eq!(poly.truncate(4), poly);
+        assert_eq!(poly.truncate(10), poly);
+
+        let poly = Polynomial::new(vec![1, 2, 0, 4]);
+        assert_eq!(poly.truncate(2).degree(), Some(1));
+
+        let other = Polynomial::new(vec![3, -1, 2]);
+        assert_eq!(poly.mul_mod_xn(&other, 3), poly.mul(&other).truncate(2));
+    }
+
+    #[test]
+    fn series_inverse() {
+        use num::Rational64;
+
+        let poly = Polynomial::new(vec![1, -1]);
+        assert_eq!(
+            poly.series_inverse(5),
+            Some(Polynomial::new(vec![1, 1, 1, 1, 1]))
+        );
+        assert_eq!(poly.series_inverse(1), Some(Polynomial::one()));
+        assert_eq!(poly.series_inverse(0), Some(Polynomial::zero()));
+
+        let r = |v: i64| Rational64::from_integer(v);
+        let poly = Polynomial::new(vec![r(2), r(3), r(1)]);
+        for n in 1..8 {
+            let inv = poly.series_inverse(n).unwrap();
+            assert!(inv.degree().unwrap() < n);
+            assert_eq!(poly.mul_mod_xn(&inv, n), Polynomial::one());
+        }
+
+        assert_eq!(Polynomial::new(vec![0, 1]).series_inverse(3), None);
+    }
+
+    #[test]
+    fn forward_difference() {
+        let poly = Polynomial::new(vec![0, 0, 1]);
+        assert_eq!(poly.forward_difference(), Polynomial::new(vec![1, 2]));
+
+        // the third difference of a cubic is 6 times its leading coefficient
+        let poly = Polynomial::new(vec![4, -1, 3, 2]);
+        let third = poly
+            .forward_difference()
+            .forward_difference()
+            .forward_difference();
+        assert_eq!(third, Polynomial::constant(12));
+        assert!(third.forward_difference().is_zero());
+
+        for x in -3..4 {
+            assert_eq!(
+                poly.forward_difference().eval_at(x),
+                poly.eval_at(x + 1) - poly.eval_at(x)
+            );
+        }
+    }
+
+    #[test]
+    fn binomial_basis() {
+        // 3x^2 - x + 2 = 2 + 2 binomial(x, 1) + 6 binomial(x, 2)
+        let poly = Polynomial::new(vec![2i64, -1, 3]);
+        assert_eq!(poly.to_binomial_basis(), vec![2, 2, 6]);
+        assert_eq!(Polynomial::from_binomial_basis(&[2, 2, 6]), poly);
+
+        let poly = Polynomial::new(vec![-4i64, 0, 5, 1]);
+        assert_eq!(
+            Polynomial::from_binomial_basis(&poly.to_binomial_basis()),
+            poly
+        );
+
+        // binomial(x, 2) itself, integer valued with fractional coefficients
+        use num::Rational64;
+        let r = |n: i64, d: i64| Rational64::new(n, d);
+        let binom = [r(0, 1), r(0, 1), r(1, 1)];
+        let poly = Polynomial::from_binomial_basis(&binom);
+        assert_eq!(poly, Polynomial::new(vec![r(0, 1), r(-1, 2), r(1, 2)]));
+        assert_eq!(poly.to_binomial_basis(), binom);
+
+        assert_eq!(Polynomial::<i64>::zero().to_binomial_basis(), vec![0]);
+        assert!(Polynomial::<i64>::from_binomial_basis(&[]).is_zero());
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn chebyshev_t() {
+        assert_eq!(Polynomial::chebyshev_t(0), Polynomial::new(vec![1.0]));
+        assert_eq!(Polynomial::chebyshev_t(1), Polynomial::new(vec![0.0, 1.0]));
+        assert_eq!(
+            Polynomial::chebyshev_t(2),
+            Polynomial::new(vec![-1.0, 0.0, 2.0])
+        );
+        assert_eq!(
+            Polynomial::chebyshev_t(3),
+            Polynomial::new(vec![0.0, -3.0, 0.0, 4.0])
+        );
+        assert_eq!(
+            Polynomial::chebyshev_t(5),
+            Polynomial::new(vec![0.0, 5.0, 0.0, -20.0, 0.0, 16.0])
+        );
+
+        // T_n(cos t) = cos(nt)
+        let t = 0.7f64;
+        assert!((Polynomial::chebyshev_t(6).eval_at(t.cos()) - (6.0 * t).cos()).abs() < 1e-12);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn eval_chebyshev() {
+        let coeffs = [0.5, -1.0, 2.0, 0.25, -3.0];
+        for x in [-1.0, -0.6, 0.0, 0.3, 0.9, 1.0] {
+            let expanded: f64 = coeffs
+                .iter()
+                .enumerate()
+                .map(|(k, c)| c * Polynomial::chebyshev_t(k).eval_at(x))
+                .sum();
+            assert!((Polynomial::eval_chebyshev(&coeffs, x) - expanded).abs() < 1e-12);
+        }
+
+        assert_eq!(Polynomial::eval_chebyshev(&[], 0.5), 0.0);
+        assert_eq!(Polynomial::eval_chebyshev(&[4.0], 0.5), 4.0);
+        assert_eq!(Polynomial::eval_chebyshev(&[0.0, 0.0, 1.0], 0.5), -0.5);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn approximate_chebyshev() {
+        let pi = core::f64::consts::PI;
+        let approx = Polynomial::approximate_chebyshev(f64::sin, 10, 0.0, pi);
+        assert_eq!(approx.degree(), Some(10));
+        let max_err = (0..=100)
+            .map(|i| pi * i as f64 / 100.0)
+            .map(|x| (approx.eval_at(x) - x.sin()).abs())
+            .fold(0.0, f64::max);
+        assert!(max_err < 1e-7);
+
+        // polynomials up to the requested degree are reproduced
+        let cubic = Polynomial::new(vec![1.0, -2.0, 0.5, 3.0]);
+        let approx = Polynomial::approximate_chebyshev(|x| cubic.eval_at(x), 3, -2.0, 5.0);
+        assert!(approx.approx_eq(&cubic, 1e-9));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    #[should_panic]
+    fn approximate_chebyshev_empty_interval() {
+        Polynomial::approximate_chebyshev(f64::sin, 3, 1.0, 1.0);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn deflate() {
+        let poly = Polynomial::from_roots(&[1.0, 2.0, 3.0]);
+        assert_eq!(poly.deflate(2.0), Ok(Polynomial::from_roots(&[1.0, 3.0])));
+        assert_eq!(poly.deflate(2.5), Err(DeflationError::NotARoot));
+
+        // a root off by rounding error still deflates, anything more doesn't
+        assert!(poly.deflate(2.0 + 2.0 * f64::EPSILON).is_ok());
+        assert_eq!(poly.deflate(2.0 + 1e-12), Err(DeflationError::NotARoot));
+        assert_eq!(poly.deflate(2.0000001), Err(DeflationError::NotARoot));
+
+        // the coefficients here are themselves rounded
+        let poly = Polynomial::from_roots(&[0.1, 0.7, 1e3]);
+        let deflated = poly.deflate(0.7).unwrap();
+        assert!(deflated.approx_eq(&Polynomial::from_roots(&[0.1, 1e3]), 1e-9));
+
+        assert_eq!(
+            Polynomial::new(vec![5.0]).deflate(1.0),
+            Err(DeflationError::NotARoot)
+        );
+        assert_eq!(
+            Polynomial::<f64>::zero().deflate(1.0),
+            Ok(Polynomial::zero())
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn count_real_roots() {
+        let poly = Polynomial::from_roots(&[1.0, 2.0, 3.0]);
+        assert_eq!(poly.count_real_roots(0.0, 4.0), 3);
+        assert_eq!(poly.count_real_roots(1.5, 2.5), 1);
+        assert_eq!(poly.count_real_roots(3.5, 10.0), 0);
+        // the interval is half open
+        assert_eq!(poly.count_real_roots(0.0, 1.0), 1);
+        assert_eq!(poly.count_real_roots(1.0, 1.9), 0);
+
+        // x^2 + 1 has no real roots at all
+        let poly = Polynomial::new(vec![1.0, 0.0, 1.0]);
+        assert_eq!(poly.count_real_roots(-100.0, 100.0), 0);
+
+        // repeated roots are counted once
+        let poly = Polynomial::from_roots(&[1.0, 1.0, -2.0]);
+        assert_eq!(poly.count_real_roots(-5.0, 5.0), 2);
+
+        // (x^2 + 4)(x - 0.5)(x + 3.25)
+        let poly = Polynomial::new(vec![4.0, 0.0, 1.0]).mul(&Polynomial::from_roots(&[0.5, -3.25]));
+        assert_eq!(poly.count_real_roots(-10.0, 10.0), 2);
+        assert_eq!(poly.count_real_roots(-1.0, 10.0), 1);
+
+        assert_eq!(Polynomial::new(vec![3.0]).count_real_roots(-1.0, 1.0), 0);
+
+        // close but distinct roots are still told apart
+        let poly = Polynomial::from_roots(&[1.0, 1.0 + 1e-6]);
+        assert_eq!(poly.count_real_roots(0.0, 5.0), 2);
+        let poly = Polynomial::from_roots(&[-2.0, 0.5, 0.5 + 1e-5, 3.0]);
+        assert_eq!(poly.count_real_roots(-5.0, 5.0), 4);
+        assert_eq!(poly.count_real_roots(0.0, 1.0), 2);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn legendre() {
+        assert_eq!(Polynomial::legendre(0), Polynomial::new(vec![1.0]));
+        assert_eq!(Polynomial::legendre(1), Polynomial::new(vec![0.0, 1.0]));
+        // (3x^2 - 1) / 2 and (5x^3 - 3x) / 2
+        assert_eq!(
+            Polynomial::legendre(2),
+            Polynomial::new(vec![-0.5, 0.0, 1.5])
+        );
+        assert_eq!(
+            Polynomial::legendre(3),
+            Polynomial::new(vec![0.0, -1.5, 0.0, 2.5])
+        );
+
+        // (35x^4 - 30x^2 + 3) / 8, and P_n(1) = 1
+        let p4 = Polynomial::legendre(4);
+        assert!(p4.approx_eq(&Polynomial::new(vec![0.375, 0.0, -3.75, 0.0, 4.375]), 1e-12));
+        for n in 0..10 {
+            assert!((Polynomial::legendre(n).eval_at(1.0) - 1.0).abs() < 1e-12);
+        }
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn hermite() {
+        assert_eq!(
+            Polynomial::hermite_physicists(0),
+            Polynomial::new(vec![1.0])
+        );
+        assert_eq!(
+            Polynomial::hermite_physicists(1),
+            Polynomial::new(vec![0.0, 2.0])
+        );
+        assert_eq!(
+            Polynomial::hermite_physicists(2),
+            Polynomial::new(vec![-2.0, 0.0, 4.0])
+        );
+        assert_eq!(
+            Polynomial::hermite_physicists(3),
+            Polynomial::new(vec![0.0, -12.0, 0.0, 8.0])
+        );
+        assert_eq!(
+            Polynomial::hermite_physicists(4),
+            Polynomial::new(vec![12.0, 0.0, -48.0, 0.0, 16.0])
+        );
+
+        assert_eq!(
+            Polynomial::hermite_probabilists(0),
+            Polynomial::new(vec![1.0])
+        );
+        assert_eq!(
+            Polynomial::hermite_probabilists(1),
+            Polynomial::new(vec![0.0, 1.0])
+        );
+        assert_eq!(
+            Polynomial::hermite_probabilists(2),
+            Polynomial::new(vec![-1.0, 0.0, 1.0])
+        );
+        assert_eq!(
+            Polynomial::hermite_probabilists(3),
+            Polynomial::new(vec![0.0, -3.0, 0.0, 1.0])
+        );
+
+        // H_n(x) = 2^(n/2) He_n(sqrt(2) x)
+        let x = 0.3f64;
+        let h = Polynomial::hermite_physicists(5).eval_at(x);
+        let he = Polynomial::hermite_probabilists(5).eval_at(2f64.sqrt() * x);
+        assert!((h - 2f64.powf(2.5) * he).abs() < 1e-9);
+    }
+
+    #[test]
+    fn descartes_sign_changes() {
+        // (x - 1)^2 (x + 1) = x^3 - x^2 - x + 1
+        let poly = Polynomial::new(vec![1, -1, -1, 1]);
+        assert_eq!(poly.descartes_sign_changes(), 2);
+        assert_eq!(poly.descartes_negative_sign_changes(), 1);
+
+        // 2x^3 - 1, zero coefficients don't count
+        let poly = Polynomial::new(vec![-1, 0, 0, 2]);
+        assert_eq!(poly.descartes_sign_changes(), 1);
+        assert_eq!(poly.descartes_negative_sign_changes(), 0);
+
+        // x^4 + x^2 + 1 has no real roots
+        let poly = Polynomial::new(vec![1.0, 0.0, 1.0, 0.0, 1.0]);
+        assert_eq!(poly.descartes_sign_changes(), 0);
+        assert_eq!(poly.descartes_negative_sign_changes(), 0);
+
+        let poly = Polynomial::from_roots(&[1, 2, 3, -4, -5]);
+        assert_eq!(poly.descartes_sign_changes(), 3);
+        assert_eq!(poly.descartes_negative_sign_changes(), 2);
+        assert_eq!(Polynomial::<i32>::zero().descartes_sign_changes(), 0);
+    }
+
+    #[test]
+    fn pseudo_div_rem() {
+        // 4 (x^2 + 1) = (2x - 1)(2x + 1) + 5
+        let a = Polynomial::new(vec![1i64, 0, 1]);
+        let b = Polynomial::new(vec![1i64, 2]);
+        assert_eq!(
+            a.pseudo_div_rem(&b),
+            (Polynomial::new(vec![-1, 2]), Polynomial::new(vec![5]))
+        );
+
+        // knuth's example, prem = -15x^4 + 3x^2 - 9 with 3^3 as the multiplier
+        let a = Polynomial::new(vec![-5i64, 2, 8, -3, -3, 0, 1, 0, 1]);
+        let b = Polynomial::new(vec![21i64, -9, -4, 0, 5, 0, 3]);
+        let (q, r) = a.pseudo_div_rem(&b);
+        assert_eq!(r, Polynomial::new(vec![-9, 0, 3, 0, -15]));
+        assert_eq!(q.mul(&b).add(&r), a.scale(27));
+
+        // 2x^3 + x^2 + 3 by 2x^2 + x, the remainder skips a degree but the
+        // multiplier is still 2^2
+        let a = Polynomial::new(vec![3i64, 0, 1, 2]);
+        let b = Polynomial::new(vec![0i64, 1, 2]);
+        let (q, r) = a.pseudo_div_rem(&b);
+        assert_eq!(
+            (q.clone(), r.clone()),
+            (Polynomial::new(vec![0, 4]), Polynomial::new(vec![12]))
+        );
+        assert_eq!(q.mul(&b).add(&r), a.scale(4));
+
+        let (q, r) = b.pseudo_div_rem(&a);
+        assert!(q.is_zero());
+        assert_eq!(r, b);
+    }
+
+    #[test]
+    #[should_panic]
+    fn from_binomial_basis_fractional_integers() {
+        // binomial(x, 2) = (x^2 - x) / 2 has no i64 coefficients
+        Polynomial::<i64>::from_binomial_basis(&[0, 0, 1]);
+    }
+
+    #[test]
+    fn from_binomial_basis_integer_round_trip() {
+        // x^14 has c_k = k! S(14, k), scaling those by 14! / k! overflows i64
+        let p = Polynomial::<i64>::one().shift_up(14);
+        assert_eq!(Polynomial::from_binomial_basis(&p.to_binomial_basis()), p);
+
+        let p = Polynomial::new(vec![3i64, -1, 0, 5, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2]);
+        assert_eq!(Polynomial::from_binomial_basis(&p.to_binomial_basis()), p);
+    }
+
+    #[test]
+    fn scale() {
+        let poly = Polynomial::new(vec![1, 2, 3]);
+        assert_eq!(poly.scale(3), Polynomial::new(vec![3, 6, 9]));
+        assert_eq!(poly.scale(-1), poly.neg());
+        assert_eq!(poly.scale(0), Polynomial::new(vec![0]));
+
+        let poly = Polynomial::new(vec![0.5, 1.0, 2.0]);
+        assert_eq!(poly.scale(2.0), Polynomial::new(vec![1.0, 2.0, 4.0]));
+        assert_eq!(poly.scale(0.0), Polynomial::new(vec![0.0]));
+    }
+
+    #[test]
+    fn pow() {
+        let poly = Polynomial::new(vec![1, 1]);
+        assert_eq!(poly.pow(0), Polynomial::new(vec![1]));
+        assert_eq!(poly.pow(1), poly);
+        assert_eq!(poly.pow(3), Polynomial::new(vec![1, 3, 3, 1]));
+        assert_eq!(poly.pow(6), poly.pow(3).mul(&poly.pow(3)));
+
+        let poly = Polynomial::new(vec![0.0]);
+        assert_eq!(poly.pow(0), Polynomial::new(vec![1.0]));
+        assert_eq!(poly.pow(5), Polynomial::new(vec![0.0]));
+    }
+
+    #[test]
+    fn div_rem() {
+        let poly1 = Polynomial::new(vec![-1.0, 0.0, 1.0]);
+        let poly2 = Polynomial::new(vec![-1.0, 1.0]);
+        let (q, r) = poly1.div_rem(&poly2);
+        assert_eq!(q, Polynomial::new(vec![1.0, 1.0]));
+        assert_eq!(r, Polynomial::new(vec![0.0]));
+
+        let poly1 = Polynomial::new(vec![3.0, 2.0, 0.0, 1.0]);
+        let poly2 = Polynomial::new(vec![1.0, 1.0]);
+        let (q, r) = poly1.div_rem(&poly2);
+        assert_eq!(q, Polynomial::new(vec![3.0, -1.0, 1.0]));
+        assert_eq!(r, Polynomial::new(vec![0.0]));
+
+        let poly1 = Polynomial::new(vec![1.0, 0.0, 2.0]);
+        let poly2 = Polynomial::new(vec![1.0, 1.0]);
+        let (q, r) = poly1.div_rem(&poly2);
+        assert_eq!(q, Polynomial::new(vec![-2.0, 2.0]));
+        assert_eq!(r, Polynomial::new(vec![3.0]));
+        assert_eq!(q.mul(&poly2).add(&r), poly1);
+
+        let poly1 = Polynomial::new(vec![4.0, 2.0]);
+        let poly2 = Polynomial::new(vec![2.0]);
+        let (q, r) = poly1.div_rem(&poly2);
+        assert_eq!(q, Polynomial::new(vec![2.0, 1.0]));
+        assert_eq!(r, Polynomial::new(vec![0.0]));
+    }
+
+    #[test]
+    #[should_panic(expected = "division by the zero polynomial")]
+    fn div_rem_by_zero() {
+        let poly1 = Polynomial::new(vec![1.0, 2.0]);
+        let poly2 = Polynomial::new(vec![0.0]);
+        poly1.div_rem(&poly2);
+    }
+
+    #[test]
+    fn synthetic_divide() {
+        let poly = Polynomial::new(vec![-1, 0, 1]);
+        let (q, r) = poly.synthetic_divide(1);
+        assert_eq!(q, Polynomial::new(vec![1, 1]));
+        assert_eq!(r, 0);
+
+        let poly = Polynomial::new(vec![5.0, -2.0, 0.0, 3.0]);
+        for root in [-2.0, 0.0, 1.5, 4.0] {
+            let (q, r) = poly.synthetic_divide(root);
+            assert_eq!(r, poly.eval_at(root));
+            assert_eq!(
+                q.mul(&Polynomial::new(vec![-root, 1.0]))
+                    .add(&Polynomial::constant(r)),
+                poly
+            );
+        }
+
+        let (q, r) = Polynomial::new(vec![7]).synthetic_divide(2);
+        assert!(q.is_zero());
+        assert_eq!(r, 7);
+    }
+
+    #[test]
+    fn rem() {
+        let cases = vec![
+            (vec![-1.0, 0.0, 1.0], vec![-1.0, 1.0]),
+            (vec![1.0, 0.0, 2.0], vec![1.0, 1.0]),
+            (vec![5.0, -3.0, 0.0, 2.0, 1.0], vec![1.0, 0.0, 1.0]),
+            (vec![1.0, 2.0], vec![0.0, 0.0, 1.0]),
+        ];
+        for (a, b) in cases {
+            let poly1 = Polynomial::new(a);
+            let poly2 = Polynomial::new(b);
+            assert_eq!(poly1.rem(&poly2), poly1.div_rem(&poly2).1);
+        }
+
+        let poly1 = Polynomial::new(vec![1.0, 2.0]);
+        let poly2 = Polynomial::new(vec![0.0, 0.0, 1.0]);
+        assert_eq!(poly1.rem(&poly2), Polynomial::new(vec![1.0, 2.0]));
+    }
+
+    #[test]
+    fn to_monic() {
+        let poly = Polynomial::new(vec![2.0, 4.0, 6.0]);
+        assert!(!poly.is_monic());
+        assert_eq!(
+            poly.to_monic(),
+            Polynomial::new(vec![1.0 / 3.0, 2.0 / 3.0, 1.0])
+        );
+        assert!(poly.to_monic().is_monic());
+
+        let poly = Polynomial::new(vec![6.0, 4.0, 2.0]);
+        assert_eq!(poly.to_monic(), Polynomial::new(vec![3.0, 2.0, 1.0]));
+
+        let zero = Polynomial::new(vec![0.0]);
+        assert_eq!(zero.to_monic(), zero);
+        assert!(!zero.is_monic());
+    }
+
+    #[test]
+    fn gcd() {
+        let poly1 = Polynomial::new(vec![-1.0, 0.0, 1.0]);
+        let poly2 = Polynomial::new(vec![-1.0, 1.0]);
+        assert_eq!(poly1.gcd(&poly2), Polynomial::new(vec![-1.0, 1.0]));
+        assert_eq!(poly2.gcd(&poly1), Polynomial::new(vec![-1.0, 1.0]));
+
+        let poly1 = Polynomial::new(vec![-2.0, 0.0, 2.0]);
+        let poly2 = Polynomial::new(vec![2.0, 4.0, 2.0]);
+        assert_eq!(poly1.gcd(&poly2), Polynomial::new(vec![1.0, 1.0]));
+
+        let poly1 = Polynomial::new(vec![1.0, 0.0, 1.0]);
+        let poly2 = Polynomial::new(vec![-1.0, 1.0]);
+        assert_eq!(poly1.gcd(&poly2), Polynomial::new(vec![1.0]));
+
+        let poly1 = Polynomial::new(vec![2.0, 4.0]);
+        let zero = Polynomial::new(vec![0.0]);
+        assert_eq!(poly1.gcd(&zero), Polynomial::new(vec![0.5, 1.0]));
+        assert_eq!(zero.gcd(&poly1), Polynomial::new(vec![0.5, 1.0]));
+        assert_eq!(zero.gcd(&zero), Polynomial::new(vec![0.0]));
+    }
+
+    #[test]
+    fn lcm() {
+        let a = Polynomial::from_roots(&[1.0, 2.0]);
+        let b = Polynomial::from_roots(&[2.0, 3.0]).scale(4.0);
+        assert_eq!(a.lcm(&b), Polynomial::from_roots(&[1.0, 2.0, 3.0]));
+        assert_eq!(b.lcm(&a), Polynomial::from_roots(&[1.0, 2.0, 3.0]));
+
+        let zero = Polynomial::new(vec![0.0]);
+        assert!(a.lcm(&zero).is_zero());
+        assert!(zero.lcm(&a).is_zero());
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn extended_gcd() {
+        let a = Polynomial::new(vec![1.0, 0.0, 1.0]);
+        let b = Polynomial::new(vec![-1.0, 1.0]);
+        let (g, s, t) = a.extended_gcd(&b);
+        assert_eq!(g, Polynomial::new(vec![1.0]));
+        assert_eq!(s.mul(&a).add(&t.mul(&b)), g);
+
+        let a = Polynomial::from_roots(&[1.0, 2.0, -4.0]);
+        let b = Polynomial::from_roots(&[1.0, -3.0]).scale(2.0);
+        let (g, s, t) = a.extended_gcd(&b);
+        assert_eq!(g, Polynomial::new(vec![-1.0, 1.0]));
+        assert!(s.mul(&a).add(&t.mul(&b)).approx_eq(&g, 1e-12));
+
+        let zero = Polynomial::new(vec![0.0]);
+        let (g, s, t) = zero.extended_gcd(&b);
+        assert_eq!(g, b.to_monic());
+        assert_eq!(s.mul(&zero).add(&t.mul(&b)), g);
+    }
+
+    #[test]
+    fn squarefree_decomposition() {
+        use num::Rational64;
+
+        let r = |v: i64| Rational64::from_integer(v);
+        let poly = Polynomial::from_roots(&[r(1), r(1), r(2)]);
+        assert_eq!(
+            poly.squarefree_decomposition(),
+            vec![
+                (Polynomial::from_roots(&[r(2)]), 1),
+                (Polynomial::from_roots(&[r(1)]), 2)
+            ]
+        );
+
+        let poly = Polynomial::from_roots(&[r(3), r(-1), r(-1), r(-1), r(0), r(0)]).scale(r(5));
+        assert_eq!(
+            poly.squarefree_decomposition(),
+            vec![
+                (Polynomial::from_roots(&[r(3)]), 1),
+                (Polynomial::from_roots(&[r(0)]), 2),
+                (Polynomial::from_roots(&[r(-1)]), 3)
+            ]
+        );
+
+        let poly = Polynomial::from_roots(&[r(1), r(2)]);
+        assert_eq!(poly.squarefree_decomposition(), vec![(poly.clone(), 1)]);
+        assert!(Polynomial::new(vec![r(4)])
+            .squarefree_decomposition()
+            .is_empty());
+    }
+
+    #[test]
+    fn resultant() {
+        use num::Rational64;
+
+        let r = |v: i64| Rational64::from_integer(v);
+        let a = Polynomial::from_roots(&[r(1), r(-2)]);
+        let b = Polynomial::from_roots(&[r(1), r(5)]);
+        assert_eq!(a.resultant(&b), r(0));
+
+        // res(x - 2, x^2 + 1) = 2^2 + 1
+        let a = Polynomial::new(vec![r(-2), r(1)]);
+        let b = Polynomial::new(vec![r(1), r(0), r(1)]);
+        assert_eq!(a.resultant(&b), r(5));
+        assert_eq!(b.resultant(&a), r(5));
+
+        // res(2x^2 + 3x + 1, x - 3) = 2 * 3^2 + 3 * 3 + 1
+        let a = Polynomial::new(vec![r(1), r(3), r(2)]);
+        let b = Polynomial::new(vec![r(-3), r(1)]);
+        assert_eq!(a.resultant(&b), r(28));
+
+        // res(x^2 - 2, 3x^3 + x) = g(sqrt 2) * g(-sqrt 2) = -(7 sqrt 2)^2
+        let a = Polynomial::new(vec![r(-2), r(0), r(1)]);
+        let b = Polynomial::new(vec![r(0), r(1), r(0), r(3)]);
+        assert_eq!(a.resultant(&b), r(-98));
+
+        assert_eq!(a.resultant(&Polynomial::new(vec![r(3)])), r(9));
+        assert_eq!(a.resultant(&Polynomial::zero()), r(0));
+    }
+
+    #[test]
+    fn discriminant() {
+        // b^2 - 4ac
+        let poly = Polynomial::new(vec![3.0, 5.0, 2.0]);
+        assert_eq!(poly.discriminant(), 1.0);
+        let poly = Polynomial::new(vec![7.0, -2.0, 3.0]);
+        assert_eq!(poly.discriminant(), -80.0);
+
+        let poly = Polynomial::from_roots(&[1.0, 1.0, -2.0]);
+        assert_eq!(poly.discriminant(), 0.0);
+        let poly = Polynomial::from_roots(&[0.0, 1.0, 3.0]);
+        assert!((poly.discriminant() - 36.0f64).abs() < 1e-9);
+
+        assert_eq!(Polynomial::new(vec![4.0, 2.0]).discriminant(), 1.0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn discriminant_of_constant() {
+        Polynomial::new(vec![4.0]).discriminant();
+    }
+
+    #[test]
+    fn companion_matrix() {
+        let poly = Polynomial::new(vec![2.0, -3.0, 1.0]);
+        assert_eq!(
+            poly.companion_matrix(),
+            vec![vec![0.0, -2.0], vec![1.0, 3.0]]
+        );
+
+        let poly = Polynomial::new(vec![4.0, -6.0, 2.0]);
+        assert_eq!(
+            poly.companion_matrix(),
+            vec![vec![0.0, -2.0], vec![1.0, 3.0]]
+        );
+
+        let poly = Polynomial::new(vec![1, 2, 3, 1]);
+        assert_eq!(
+            poly.companion_matrix(),
+            vec![vec![0, 0, -1], vec![1, 0, -2], vec![0, 1, -3]]
+        );
+
+        assert!(Polynomial::new(vec![5.0]).companion_matrix().is_empty());
+    }
+
+    #[test]
+    fn derivative() {
+        let poly = Polynomial::new(vec![0, 0, 0, 1]);
+        assert_eq!(poly.derivative(), Polynomial::new(vec![0, 0, 3]));
+
+        let poly = Polynomial::new(vec![5.0, 1.0, 2.0, 3.0]);
+        assert_eq!(poly.derivative(), Polynomial::new(vec![1.0, 4.0, 9.0]));
+
+        let poly = Polynomial::new(vec![7]);
+        assert_eq!(poly.derivative(), Polynomial::new(vec![0]));
+        let poly = Polynomial::new(vec![0]);
+        assert_eq!(poly.derivative(), Polynomial::new(vec![0]));
+    }
+
+    #[test]
+    fn integral() {
+        let poly = Polynomial::new(vec![0.0, 0.0, 3.0]);
+        assert_eq!(
+            poly.integral(0.0),
+            Polynomial::new(vec![0.0, 0.0, 0.0, 1.0])
+        );
+        assert_eq!(
+            poly.integral(2.0),
+            Polynomial::new(vec![2.0, 0.0, 0.0, 1.0])
+        );
+        assert_eq!(poly.integral(0.0).derivative(), poly);
+
+        let poly = Polynomial::new(vec![0.0]);
+        assert_eq!(poly.integral(0.0), Polynomial::new(vec![0.0]));
+        assert_eq!(poly.integral(5.0), Polynomial::new(vec![5.0]));
+    }
+
+    #[test]
+    fn integrate_between() {
+        let poly = Polynomial::new(vec![0.0, 0.0, 3.0]);
+        assert_eq!(poly.integrate_between(0.0, 2.0), 8.0);
+        assert_eq!(poly.integrate_between(2.0, 0.0), -8.0);
+        assert_eq!(poly.integrate_between(1.0, 1.0), 0.0);
+
+        let poly = Polynomial::new(vec![1.0, 2.0]);
+        assert_eq!(poly.integrate_between(-1.0, 3.0), 12.0);
+    }
+
+    #[test]
+    fn compose() {
+        let poly = Polynomial::new(vec![0, 0, 1]);
+        let inner = Polynomial::new(vec![1, 1]);
+        assert_eq!(poly.compose(&inner), Polynomial::new(vec![1, 2, 1]));
+
+        let poly = Polynomial::new(vec![3, -2, 0, 5]);
+        assert_eq!(poly.compose(&Polynomial::new(vec![0, 1])), poly);
+        assert_eq!(
+            poly.compose(&Polynomial::new(vec![2])),
+            Polynomial::new(vec![poly.eval_at(2)])
+        );
+
+        let poly = Polynomial::new(vec![1.0, 2.0, 3.0]);
+        let inner = Polynomial::new(vec![-1.0, 0.0, 2.0]);
+        let composed = poly.compose(&inner);
+        for x in [-2.0, -0.5, 0.0, 1.0, 3.0] {
+            assert_eq!(composed.eval_at(x), poly.eval_at(inner.eval_at(x)));
+        }
+    }
+
+    #[test]
+    fn shift() {
+        let poly = Polynomial::new(vec![0, 0, 1]);
+        assert_eq!(poly.shift(1), Polynomial::new(vec![1, 2, 1]));
+        assert_eq!(poly.shift(0), poly);
+
+        let poly = Polynomial::new(vec![1.0f64, -3.0, 0.5, 2.0]);
+        assert_eq!(poly.shift(0.0), poly);
+        let shifted = poly.shift(-1.5);
+        for x in [-2.0, 0.0, 0.5, 4.0] {
+            assert!((shifted.eval_at(x) - poly.eval_at(x - 1.5)).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn mul_scalar_op() {
+        let poly = Polynomial::new(vec![1, 2, 3]);
+        assert_eq!(&poly * 3, Polynomial::new(vec![3, 6, 9]));
+        let zero = poly.coefficient(5);
+        assert_eq!(&poly * zero, Polynomial::new(vec![0]));
+        assert_eq!(
+            Polynomial::new(vec![1, 2, 3]) * 3,
+            Polynomial::new(vec![3, 6, 9])
+        );
+
+        let poly = Polynomial::new(vec![0.5, -1.0]);
+        assert_eq!(poly * 2.0, Polynomial::new(vec![1.0, -2.0]));
+    }
+
+    #[test]
+    fn div_scalar_op() {
+        let poly = Polynomial::new(vec![2.0, 4.0, 8.0]);
+        assert_eq!(&poly / 2.0, Polynomial::new(vec![1.0, 2.0, 4.0]));
+
+        let monic = &poly / poly.leading_coefficient();
+        assert_eq!(monic, Polynomial::new(vec![0.25, 0.5, 1.0]));
+        assert!(monic.is_monic());
+        assert_eq!(monic, poly.to_monic());
+
+        assert_eq!(
+            Polynomial::new(vec![3.0, -1.5]) / -1.5,
+            Polynomial::new(vec![-2.0, 1.0])
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "division by a zero scalar")]
+    fn div_scalar_by_zero() {
+        let _ = Polynomial::new(vec![1.0, 2.0]) / 0.0;
+    }
+
+    #[test]
+    fn eval() {
+        let poly = Polynomial::new(vec![1, 2, 3]);
+        assert_eq!(poly.eval_at(8), 209);
+
+        let poly = Polynomial::new(vec![1i64, 4, 10, 16, 22, 22, 15]);
+        assert_eq!(poly.eval_at(29), 9389554026);
+    }
+
+    #[test]
+    fn map_coefficients() {
+        let poly = Polynomial::new(vec![1, -2, 0, 3]);
+        let floats = poly.map_coefficients(|c| c as f64);
+        assert_eq!(floats, Polynomial::new(vec![1.0, -2.0, 0.0, 3.0]));
+        for x in [-2, 0, 1, 5] {
+            assert_eq!(floats.eval_at(x as f64), poly.eval_at(x) as f64);
+        }
+
+        let poly = Polynomial::new(vec![7, 10, 5]);
+        assert_eq!(poly.map_coefficients(|c| c % 5), Polynomial::new(vec![2]));
+
+        let poly = Polynomial::new(vec![0.4, 1.6]);
+        assert_eq!(
+            poly.map_coefficients(|c: f64| c.round()),
+            Polynomial::new(vec![0.0, 2.0])
+        );
+    }
+
+    #[test]
+    fn cast() {
+        let poly = Polynomial::new(vec![1i32, 2, 3]);
+        let wide: Polynomial<i64> = poly.cast();
+        assert_eq!(wide, Polynomial::new(vec![1i64, 2, 3]));
+        for x in [-4, 0, 9] {
+            assert_eq!(wide.eval_at(x as i64), poly.eval_at(x) as i64);
+        }
+
+        // evaluating in i32 would overflow
+        assert_eq!(wide.eval_at(100_000), 30_000_200_001);
+
+        let floats: Polynomial<f64> = poly.cast();
+        assert_eq!(floats, Polynomial::new(vec![1.0, 2.0, 3.0]));
+    }
+
+    #[test]
+    fn eval_all() {
+        let poly = Polynomial::new(vec![1, -2, 0, 3]);
+        let xs = [-3, -1, 0, 2, 5];
+        let expected: Vec<i32> = xs.iter().map(|x| poly.eval_at(*x)).collect();
+        assert_eq!(poly.eval_all(&xs), expected);
+        assert_eq!(poly.eval_all(&[]), Vec::<i32>::new());
+    }
+
+    #[test]
+    fn eval_derivative_at() {
+        let poly = Polynomial::new(vec![4, -3, 2, 5]);
+        for x in [-2, -1, 0, 1, 3, 7] {
+            assert_eq!(poly.eval_derivative_at(x), poly.derivative().eval_at(x));
+        }
+
+        let poly = Polynomial::new(vec![0.5, 1.5, 0.0, -2.0]);
+        for x in [-1.5, 0.0, 0.25, 2.0] {
+            assert_eq!(poly.eval_derivative_at(x), poly.derivative().eval_at(x));
+        }
+
+        assert_eq!(Polynomial::new(vec![7]).eval_derivative_at(3), 0);
+    }
+
+    #[test]
+    fn eval_with_derivative() {
+        let poly = Polynomial::new(vec![4, -3, 2, 5]);
+        for x in [-2, -1, 0, 1, 3, 7] {
+            assert_eq!(
+                poly.eval_with_derivative(x),
+                (poly.eval_at(x), poly.eval_derivative_at(x))
+            );
+        }
+
+        let poly = Polynomial::new(vec![0.5, 1.5, 0.0, -2.0]);
+        for x in [-1.5, 0.0, 0.25, 2.0] {
+            assert_eq!(
+                poly.eval_with_derivative(x),
+                (poly.eval_at(x), poly.eval_derivative_at(x))
+            );
+        }
+    }
+
+    #[test]
+    fn try_interpolate_from() {
+        let pts = vec![(1.0, 325.0), (3.0, 2383.0), (5.0, 6609.0)];
+        let p = Polynomial::try_interpolate_from(pts.clone()).unwrap();
+        assert_eq!(p, Polynomial::interpolate_from(pts));
+
+        let pts: Vec<(f64, f64)> = vec![];
+        let err = Polynomial::try_interpolate_from(pts).unwrap_err();
+        assert_eq!(err, InterpolationError::EmptyPoints);
+
+        let pts = vec![(1.0, 325.0), (3.0, 2383.0), (1.0, 7.0)];
+        let err = Polynomial::try_interpolate_from(pts).unwrap_err();
+        assert_eq!(err, InterpolationError::DuplicateX(1.0));
+        assert_eq!(err.to_string(), "duplicate x-coordinate 1");
+    }
+
+    #[test]
+    fn interpolate_duplicate_x() {
+        let pts = vec![(2.0, 1.0), (2.0, 3.0)];
+        let err = Polynomial::try_interpolate_from(pts).unwrap_err();
+        assert_eq!(err, InterpolationError::DuplicateX(2.0));
+
+        let pts = vec![(4, 1), (0, 5), (4, 1)];
+        let err = Polynomial::try_interpolate_from(pts).unwrap_err();
+        assert_eq!(err, InterpolationError::DuplicateX(4));
+    }
+
+    #[test]
+    #[should_panic(expected = "duplicate x-coordinates")]
+    fn interpolate_from_duplicate_x() {
+        Polynomial::interpolate_from(vec![(2.0, 1.0), (2.0, 3.0)]);
+    }
+
+    #[test]
+    fn try_interpolate_newton() {
+        let pts = vec![(1.0, 325.0), (3.0, 2383.0), (5.0, 6609.0)];
+        let p = Polynomial::try_interpolate_newton(pts.clone()).unwrap();
+        assert_eq!(p, Polynomial::interpolate_newton(pts));
+
+        let pts: Vec<(f64, f64)> = vec![];
+        let err = Polynomial::try_interpolate_newton(pts).unwrap_err();
+        assert_eq!(err, InterpolationError::EmptyPoints);
+
+        // the duplicates are not neighbours, so they only meet in the last
+        // divided difference
+        let pts = vec![(1.0, 325.0), (3.0, 2383.0), (1.0, 7.0)];
+        let err = Polynomial::try_interpolate_newton(pts).unwrap_err();
+        assert_eq!(err, InterpolationError::DuplicateX(1.0));
+
+        let pts = vec![(4, 1), (0, 5), (4, 1)];
+        let err = Polynomial::try_interpolate_newton(pts).unwrap_err();
+        assert_eq!(err, InterpolationError::DuplicateX(4));
+    }
+
+    #[test]
+    #[should_panic(expected = "duplicate x-coordinates")]
+    fn interpolate_newton_duplicate_x() {
+        Polynomial::interpolate_newton(vec![(2, 1), (2, 3)]);
+    }
+
+    #[test]
+    fn barycentric() {
+        let pts = vec![(2.0, 1083.0), (5.0, 6609.0), (0.0, 533.0), (-1.0, 20.0)];
+        let interp = BarycentricInterpolator::new(pts.clone()).unwrap();
+        let poly = Polynomial::interpolate_from(pts.clone());
+
+        for x in [-3.0f64, -0.5, 1.0, 2.5, 4.0, 10.0] {
+            let expected = poly.eval_at(x);
+            assert!((interp.eval_at(x) - expected).abs() < 1e-9 * expected.abs().max(1.0));
+        }
+        for (x, y) in pts {
+            assert_eq!(interp.eval_at(x), y);
+        }
+
+        let pts: Vec<(f64, f64)> = vec![];
+        assert!(matches!(
+            BarycentricInterpolator::new(pts),
+            Err(InterpolationError::EmptyPoints)
+        ));
+        assert!(matches!(
+            BarycentricInterpolator::new(vec![(1.0, 2.0), (1.0, 3.0)]),
+            Err(InterpolationError::DuplicateX(x)) if x == 1.0
+        ));
+    }
+
+    #[test]
+    fn vandermonde() {
+        assert_eq!(
+            Polynomial::vandermonde(&[1, 2, 3], 2),
+            vec![vec![1, 1, 1], vec![1, 2, 4], vec![1, 3, 9]]
+        );
+        assert_eq!(Polynomial::vandermonde(&[2.0], 0), vec![vec![1.0]]);
+        assert!(Polynomial::<i32>::vandermonde(&[], 3).is_empty());
+    }
+
+    #[test]
+    fn interpolate_newton() {
+        let pts = vec![(1.0, 325.0), (3.0, 2383.0), (5.0, 6609.0)];
+        let p = Polynomial::interpolate_newton(pts.clone());
+        assert_eq!(p, Polynomial::interpolate_from(pts));
+        assert_eq!(p, Polynomial::new(vec![109.0, -55.0, 271.0]));
+
+        let pts = vec![(2.0, 1083.0), (5.0, 6609.0), (0.0, 533.0)];
+        let p = Polynomial::interpolate_newton(pts);
+        assert_eq!(p.eval_at(0.0), 533.0);
+        assert_eq!(p.eval_at(2.0), 1083.0);
+        assert_eq!(p.eval_at(5.0), 6609.0);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn fit_least_squares() {
+        let pts: Vec<(f64, f64)> = (0..11)
+            .map(|i| {
+                let x = i as f64;
+                let noise = if i % 2 == 0 { 0.01 } else { -0.01 };
+                (x, 2.0 * x + 1.0 + noise)
+            })
+            .collect();
+        let line = Polynomial::fit_least_squares(&pts, 1).unwrap();
+        assert!((line.coefficient(1) - 2.0).abs() < 1e-2);
+        assert!((line.coefficient(0) - 1.0).abs() < 5e-2);
+
+        let pts = vec![(1.0f64, 325.0), (3.0, 2383.0), (5.0, 6609.0)];
+        let quad = Polynomial::fit_least_squares(&pts, 2).unwrap();
+        for (c, e) in quad.coefficients().iter().zip([109.0, -55.0, 271.0]) {
+            assert!((c - e).abs() < 1e-6);
+        }
+
+        let pts = vec![(1.0, 2.0), (2.0, 3.0)];
+        assert_eq!(
+            Polynomial::fit_least_squares(&pts, 2).unwrap_err(),
+            FitError::Underdetermined
+        );
+        let pts = vec![(1.0, 2.0), (1.0, 3.0), (1.0, 4.0)];
+        assert_eq!(
+            Polynomial::fit_least_squares(&pts, 1).unwrap_err(),
+            FitError::Singular
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn real_roots_newton() {
+        let poly = Polynomial::from_roots(&[1.0f64, 2.0, 3.0]);
+        let mut roots = poly.real_roots_newton(&[0.0, 1.6, 5.0], 100, 1e-12);
+        roots.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        assert_eq!(roots.len(), 3);
+        for (root, expected) in roots.iter().zip([1.0, 2.0, 3.0]) {
+            assert!((root - expected).abs() < 1e-9);
+        }
+
+        // x^2 + 1 has no real roots
+        let poly = Polynomial::new(vec![1.0, 0.0, 1.0]);
+        assert!(poly.real_roots_newton(&[0.5, 2.0], 50, 1e-12).is_empty());
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn approx_eq() {
+        let poly1 = Polynomial::new(vec![1.0, 2.0, 3.0]);
+        let poly2 = Polynomial::new(vec![1.0 + 1e-12, 2.0 - 1e-12, 3.0 + 1e-12]);
+        assert!(poly1 != poly2);
+        assert!(poly1.approx_eq(&poly2, 1e-9));
+        assert!(!poly1.approx_eq(&poly2, 1e-13));
+
+        // a tiny leading coefficient left over from rounding still compares equal
+        let poly3 = Polynomial::new(vec![1.0, 2.0, 3.0, 1e-12]);
+        assert!(poly1.approx_eq(&poly3, 1e-9));
+        assert!(poly3.approx_eq(&poly1, 1e-9));
+        assert!(!poly1.approx_eq(&Polynomial::new(vec![1.0, 2.0]), 1e-9));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn roots_quadratic() {
+        let poly = Polynomial::new(vec![2.0, -3.0, 1.0]);
+        let (r1, r2) = poly.roots_quadratic().unwrap();
+        assert_eq!((r1, r2), (Complex::new(2.0, 0.0), Complex::new(1.0, 0.0)));
+
+        let poly = Polynomial::new(vec![1.0, -2.0, 1.0]);
+        let (r1, r2) = poly.roots_quadratic().unwrap();
+        assert_eq!((r1, r2), (Complex::new(1.0, 0.0), Complex::new(1.0, 0.0)));
+
+        let poly = Polynomial::new(vec![5.0, 2.0, 1.0]);
+        let (r1, r2) = poly.roots_quadratic().unwrap();
+        assert_eq!(
+            (r1, r2),
+            (Complex::new(-1.0, 2.0), Complex::new(-1.0, -2.0))
+        );
+
+        // naive formula loses the small root to cancellation here
+        let poly = Polynomial::new(vec![1.0, 1e8, 1.0]);
+        let (r1, r2) = poly.roots_quadratic().unwrap();
+        assert!((r1.re + 1e8).abs() < 1e-6);
+        assert!((r2.re + 1e-8).abs() < 1e-20);
+
+        assert!(Polynomial::new(vec![0.0, 0.0, 1.0])
+            .roots_quadratic()
+            .is_some());
+        assert!(Polynomial::new(vec![1.0, 2.0]).roots_quadratic().is_none());
+        assert!(Polynomial::new(vec![1.0, 2.0, 3.0, 4.0])
+            .roots_quadratic()
+            .is_none());
+    }
+
+    #[test]
+    fn content() {
+        let poly = Polynomial::new(vec![2i64, 4, 6]);
+        assert_eq!(poly.content(), 2);
+        assert_eq!(poly.primitive_part(), Polynomial::new(vec![1, 2, 3]));
+
+        let poly = Polynomial::new(vec![-6i64, 0, 9, -12]);
+        assert_eq!(poly.content(), 3);
+        assert_eq!(poly.primitive_part(), Polynomial::new(vec![-2, 0, 3, -4]));
+        assert_eq!(poly.primitive_part().scale(poly.content()), poly);
+
+        let poly = Polynomial::new(vec![0i64]);
+        assert_eq!(poly.content(), 0);
+        assert_eq!(poly.primitive_part(), poly);
+    }
+
+    #[test]
+    fn rational_roots() {
+        // (2x - 1)(x + 3)
+        let poly = Polynomial::new(vec![-3i64, 5, 2]);
+        assert_eq!(poly.rational_roots(), vec![(-3, 1), (1, 2)]);
+
+        // x^2 (x - 2)(3x + 2)
+        let poly = Polynomial::new(vec![0i64, 0, -4, -4, 3]);
+        assert_eq!(poly.rational_roots(), vec![(-2, 3), (0, 1), (2, 1)]);
+
+        let poly = Polynomial::new(vec![1i64, 0, 1]);
+        assert!(poly.rational_roots().is_empty());
+        assert!(Polynomial::new(vec![0i64]).rational_roots().is_empty());
+    }
+
+    #[test]
+    fn rational_roots_large_coefficients() {
+        // q^n no longer fits in i128 here
+        let poly = Polynomial::new(vec![1i64, 0, 0, 0, 10_000_000_000]);
+        assert!(poly.rational_roots().is_empty());
+
+        // (10^10 x - 3)(x + 7)
+        let poly = Polynomial::new(vec![-21i64, 69_999_999_997, 10_000_000_000]);
+        assert_eq!(poly.rational_roots(), vec![(-7, 1), (3, 10_000_000_000)]);
+
+        // x^2 - 10^12, enumerating every divisor candidate up to 10^12 would hang
+        let poly = Polynomial::new(vec![-1_000_000_000_000i64, 0, 1]);
+        assert_eq!(poly.rational_roots(), vec![(-1_000_000, 1), (1_000_000, 1)]);
+
+        // prime and semiprime constant terms, far past what trial division
+        // up to the square root gets through
+        let poly = Polynomial::new(vec![(1i64 << 61) - 1, 1, 1]);
+        assert!(poly.rational_roots().is_empty());
+        let poly = Polynomial::new(vec![i64::MIN, 0, 1]);
+        assert!(poly.rational_roots().is_empty());
+
+        // (x - (2^31 - 1))(x - 2147483629)
+        let (a, b) = (2_147_483_647i64, 2_147_483_629i64);
+        let poly = Polynomial::new(vec![a * b, -(a + b), 1]);
+        assert_eq!(poly.rational_roots(), vec![(b, 1), (a, 1)]);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn big_coefficients() {
+        use num::{BigInt, BigRational};
+
+        let big = |v: i64| BigInt::from(v);
+        let poly = Polynomial::new(vec![big(1), big(10_000_000_000)]);
+        let squared = poly.mul(&poly);
+        assert_eq!(
+            squared,
+            Polynomial::new(vec![
+                big(1),
+                big(20_000_000_000),
+                big(10_000_000_000).pow(2)
+            ])
+        );
+        assert_eq!(
+            squared.eval_at(big(1_000)),
+            "100000000000020000000000001".parse::<BigInt>().unwrap()
+        );
+
+        // exact interpolation through values far outside the i64 range
+        let rat = |v: &str| BigRational::from_integer(v.parse().unwrap());
+        let pts = vec![
+            (rat("0"), rat("100000000000000000000")),
+            (rat("1"), rat("100000000000000000003")),
+            (rat("2"), rat("100000000000000000010")),
+        ];
+        let p = Polynomial::interpolate_from(pts.clone());
+        assert_eq!(
+            p,
+            Polynomial::new(vec![rat("100000000000000000000"), rat("1"), rat("2")])
+        );
+        for (x, y) in pts {
+            assert_eq!(p.eval_at(x), y);
+        }
     }
 
     #[test]
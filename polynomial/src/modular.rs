@@ -0,0 +1,716 @@
+use alloc::vec;
+use alloc::vec::Vec;
+
+// polynomial over the prime field GF(p), coefficients are kept reduced into
+// [0, p) in ascending order with trailing zeros trimmed
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PrimeFieldPoly {
+    p: u64,
+    coefs_: Vec<u64>,
+}
+
+// deterministic miller-rabin, the first twelve primes as witnesses are
+// enough for every u64
+fn is_prime(p: u64) -> bool {
+    const WITNESSES: [u64; 12] = [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37];
+
+    if p < 2 {
+        return false;
+    }
+    for w in WITNESSES {
+        if p.is_multiple_of(w) {
+            return p == w;
+        }
+    }
+
+    let s = (p - 1).trailing_zeros();
+    let d = (p - 1) >> s;
+    'witness: for w in WITNESSES {
+        let mut x = pow_mod(w, d, p);
+        if x == 1 || x == p - 1 {
+            continue;
+        }
+        for _ in 1..s {
+            x = mul_mod(x, x, p);
+            if x == p - 1 {
+                continue 'witness;
+            }
+        }
+        return false;
+    }
+    true
+}
+
+// prime factors of n repeated by multiplicity, in no particular order;
+// trial division strips the small ones and pollard's rho splits the rest
+pub(crate) fn prime_factors(mut n: u64) -> Vec<u64> {
+    let mut res = Vec::new();
+    for d in 2..1000 {
+        while n.is_multiple_of(d) {
+            res.push(d);
+            n /= d;
+        }
+    }
+
+    let mut stack = vec![n];
+    while let Some(m) = stack.pop() {
+        if m == 1 {
+            continue;
+        }
+        if is_prime(m) {
+            res.push(m);
+        } else {
+            let d = pollard_rho(m);
+            stack.push(d);
+            stack.push(m / d);
+        }
+    }
+    res
+}
+
+// a nontrivial divisor of an odd composite n; floyd's cycle finding on
+// x^2 + c, moving on to the next c when the cycle closes on n itself
+fn pollard_rho(n: u64) -> u64 {
+    let mut c = 1;
+    loop {
+        let step = |x: u64| add_mod(mul_mod(x, x, n), c, n);
+        let (mut x, mut y, mut d) = (2, 2, 1);
+        while d == 1 {
+            x = step(x);
+            y = step(step(y));
+            d = num::integer::gcd(x.abs_diff(y), n);
+        }
+        if d != n {
+            return d;
+        }
+        c += 1;
+    }
+}
+
+// sums and products go through u128, p may be close to 2^64
+fn add_mod(a: u64, b: u64, p: u64) -> u64 {
+    ((a as u128 + b as u128) % p as u128) as u64
+}
+
+fn sub_mod(a: u64, b: u64, p: u64) -> u64 {
+    ((a as u128 + p as u128 - b as u128) % p as u128) as u64
+}
+
+fn mul_mod(a: u64, b: u64, p: u64) -> u64 {
+    (a as u128 * b as u128 % p as u128) as u64
+}
+
+fn pow_mod(mut base: u64, mut exp: u64, p: u64) -> u64 {
+    let mut res = 1 % p;
+    while exp > 0 {
+        if exp & 1 == 1 {
+            res = mul_mod(res, base, p);
+        }
+        base = mul_mod(base, base, p);
+        exp >>= 1;
+    }
+    res
+}
+
+// fermat's little theorem, a^(p - 2) is the inverse of a nonzero a
+fn inv_mod(a: u64, p: u64) -> u64 {
+    pow_mod(a, p - 2, p)
+}
+
+impl PrimeFieldPoly {
+    pub fn new(p: u64, coefs: Vec<u64>) -> Self {
+        if !is_prime(p) {
+            panic!("modulus {} is not prime", p);
+        }
+        PrimeFieldPoly::reduced(p, coefs.into_iter().map(|c| c % p).collect())
+    }
+
+    // coefficients already in [0, p), only trimming is left
+    fn reduced(p: u64, mut coefs: Vec<u64>) -> Self {
+        while coefs.len() > 1 && coefs[coefs.len() - 1] == 0 {
+            coefs.pop();
+        }
+        if coefs.is_empty() {
+            coefs.push(0);
+        }
+        PrimeFieldPoly { p, coefs_: coefs }
+    }
+
+    pub fn modulus(&self) -> u64 {
+        self.p
+    }
+
+    pub fn coefficients(&self) -> &[u64] {
+        &self.coefs_
+    }
+
+    pub fn coefficient(&self, i: usize) -> u64 {
+        self.coefs_.get(i).copied().unwrap_or(0)
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.coefs_ == [0]
+    }
+
+    pub fn degree(&self) -> Option<usize> {
+        if self.is_zero() {
+            None
+        } else {
+            Some(self.coefs_.len() - 1)
+        }
+    }
+
+    pub fn leading_coefficient(&self) -> u64 {
+        self.coefs_[self.coefs_.len() - 1]
+    }
+
+    fn check_field(&self, other: &Self) {
+        if self.p != other.p {
+            panic!(
+                "polynomials over GF({}) and GF({}) do not mix",
+                self.p, other.p
+            );
+        }
+    }
+
+    pub fn add(&self, other: &Self) -> Self {
+        self.check_field(other);
+        let len = core::cmp::max(self.coefs_.len(), other.coefs_.len());
+        let coefs = (0..len)
+            .map(|i| add_mod(self.coefficient(i), other.coefficient(i), self.p))
+            .collect();
+        PrimeFieldPoly::reduced(self.p, coefs)
+    }
+
+    pub fn mul(&self, other: &Self) -> Self {
+        self.check_field(other);
+        let mut coefs = vec![0; self.coefs_.len() + other.coefs_.len() - 1];
+
+        for (i, a) in self.coefs_.iter().enumerate() {
+            for (j, b) in other.coefs_.iter().enumerate() {
+                coefs[i + j] = add_mod(coefs[i + j], mul_mod(*a, *b, self.p), self.p);
+            }
+        }
+
+        PrimeFieldPoly::reduced(self.p, coefs)
+    }
+
+    // long division, every step divides by the inverse of the divisor's
+    // leading coefficient
+    pub fn div_rem(&self, divisor: &Self) -> (Self, Self) {
+        self.check_field(divisor);
+        if divisor.is_zero() {
+            panic!("polynomial division by the zero polynomial");
+        }
+
+        let p = self.p;
+        let d = &divisor.coefs_;
+        let mut rem = self.coefs_.clone();
+        if rem.len() < d.len() {
+            return (PrimeFieldPoly::reduced(p, vec![0]), self.clone());
+        }
+
+        let inv = inv_mod(divisor.leading_coefficient(), p);
+        let mut quot = vec![0; rem.len() - d.len() + 1];
+
+        for i in (0..quot.len()).rev() {
+            let q = mul_mod(rem[i + d.len() - 1], inv, p);
+            quot[i] = q;
+            for (j, dj) in d.iter().enumerate() {
+                rem[i + j] = sub_mod(rem[i + j], mul_mod(q, *dj, p), p);
+            }
+        }
+
+        (
+            PrimeFieldPoly::reduced(p, quot),
+            PrimeFieldPoly::reduced(p, rem),
+        )
+    }
+
+    pub fn sub(&self, other: &Self) -> Self {
+        self.check_field(other);
+        let len = core::cmp::max(self.coefs_.len(), other.coefs_.len());
+        let coefs = (0..len)
+            .map(|i| sub_mod(self.coefficient(i), other.coefficient(i), self.p))
+            .collect();
+        PrimeFieldPoly::reduced(self.p, coefs)
+    }
+
+    pub fn to_monic(&self) -> Self {
+        if self.is_zero() {
+            return self.clone();
+        }
+        let inv = inv_mod(self.leading_coefficient(), self.p);
+        let coefs = self
+            .coefs_
+            .iter()
+            .map(|c| mul_mod(*c, inv, self.p))
+            .collect();
+        PrimeFieldPoly::reduced(self.p, coefs)
+    }
+
+    // monic, so the result is unique
+    pub fn gcd(&self, other: &Self) -> Self {
+        let mut a = self.clone();
+        let mut b = other.clone();
+        while !b.is_zero() {
+            let r = a.div_rem(&b).1;
+            a = b;
+            b = r;
+        }
+        a.to_monic()
+    }
+
+    pub fn derivative(&self) -> Self {
+        let coefs = (1..self.coefs_.len())
+            .map(|i| mul_mod(self.coefs_[i], i as u64 % self.p, self.p))
+            .collect();
+        PrimeFieldPoly::reduced(self.p, coefs)
+    }
+
+    // square and multiply, reducing mod the modulus polynomial after
+    // every product
+    fn pow_mod(&self, mut exp: u64, modulus: &Self) -> Self {
+        let mut base = self.div_rem(modulus).1;
+        let mut res = PrimeFieldPoly::reduced(self.p, vec![1]).div_rem(modulus).1;
+        while exp > 0 {
+            if exp & 1 == 1 {
+                res = res.mul(&base).div_rem(modulus).1;
+            }
+            base = base.mul(&base).div_rem(modulus).1;
+            exp >>= 1;
+        }
+        res
+    }
+
+    // a factor of degree d divides x^(p^d) - x, so f is irreducible exactly
+    // when gcd(x^(p^d) - x, f) = 1 for every d up to half its degree
+    pub fn is_irreducible_gf(&self, p: u64) -> bool {
+        if self.p != p {
+            panic!("polynomial is over GF({}), not GF({})", self.p, p);
+        }
+        let n = match self.degree() {
+            Some(n) if n > 0 => n,
+            _ => return false,
+        };
+
+        let x = PrimeFieldPoly::reduced(p, vec![0, 1]);
+        let mut frobenius = x.clone();
+        for _ in 0..n / 2 {
+            frobenius = frobenius.pow_mod(p, self);
+            if self.gcd(&frobenius.sub(&x)).coefs_.len() > 1 {
+                return false;
+            }
+        }
+        true
+    }
+}
+
+// basis of the vectors v with v * m = 0, by row reducing the transpose
+fn left_nullspace(m: &[Vec<u64>], p: u64) -> Vec<Vec<u64>> {
+    let n = m.len();
+    let mut a: Vec<Vec<u64>> = (0..n)
+        .map(|i| m.iter().map(|row| row[i]).collect())
+        .collect();
+
+    let mut pivots = Vec::new();
+    let mut row = 0;
+    for col in 0..n {
+        let pivot = match (row..n).find(|r| a[*r][col] != 0) {
+            Some(r) => r,
+            None => continue,
+        };
+        a.swap(row, pivot);
+
+        let inv = inv_mod(a[row][col], p);
+        for v in a[row].iter_mut() {
+            *v = mul_mod(*v, inv, p);
+        }
+        let pivot_row = a[row].clone();
+        for (r, other) in a.iter_mut().enumerate() {
+            if r != row && other[col] != 0 {
+                let factor = other[col];
+                for (v, pv) in other.iter_mut().zip(pivot_row.iter()) {
+                    *v = sub_mod(*v, mul_mod(factor, *pv, p), p);
+                }
+            }
+        }
+
+        pivots.push(col);
+        row += 1;
+    }
+
+    // one basis vector per free column
+    (0..n)
+        .filter(|col| !pivots.contains(col))
+        .map(|free| {
+            let mut v = vec![0; n];
+            v[free] = 1;
+            for (r, pc) in pivots.iter().enumerate() {
+                v[*pc] = (p - a[r][free]) % p;
+            }
+            v
+        })
+        .collect()
+}
+
+// fields up to this size split the factors by trying every shift g - s of
+// the kernel vectors, larger ones use random kernel elements
+const SPLIT_SEARCH_LIMIT: u64 = 64;
+
+// berlekamp's algorithm on a monic squarefree polynomial
+fn berlekamp(f: &PrimeFieldPoly) -> Vec<PrimeFieldPoly> {
+    let p = f.p;
+    let n = f.coefs_.len() - 1;
+
+    // row i of q - i holds x^(ip) mod f minus x^i
+    let x = PrimeFieldPoly::reduced(p, vec![0, 1]);
+    let xp = x.pow_mod(p, f);
+    let mut power = PrimeFieldPoly::reduced(p, vec![1]);
+    let mut m = Vec::with_capacity(n);
+    for i in 0..n {
+        let mut row: Vec<u64> = (0..n).map(|j| power.coefficient(j)).collect();
+        row[i] = sub_mod(row[i], 1, p);
+        m.push(row);
+        power = power.mul(&xp).div_rem(f).1;
+    }
+
+    // the nullspace dimension is the number of irreducible factors, its
+    // first basis vector is the constant 1 which splits nothing
+    let basis = left_nullspace(&m, p);
+    let mut factors = vec![f.clone()];
+
+    if p <= SPLIT_SEARCH_LIMIT {
+        for v in basis.iter().skip(1) {
+            if factors.len() == basis.len() {
+                break;
+            }
+            let g = PrimeFieldPoly::reduced(p, v.clone());
+            for s in 0..p {
+                let gs = g.sub(&PrimeFieldPoly::reduced(p, vec![s]));
+                factors = split(factors, &gs);
+                if factors.len() == basis.len() {
+                    break;
+                }
+            }
+        }
+        return factors;
+    }
+
+    // cantor-zassenhaus: a random g from the kernel is a random residue mod
+    // every irreducible factor, and g^((p - 1) / 2) is 1 on about half of
+    // them, so each round splits a given pair of factors with odds near 1/2
+    let one = PrimeFieldPoly::reduced(p, vec![1]);
+    let mut state = 0;
+    while factors.len() < basis.len() {
+        let mut coefs = vec![0; n];
+        for v in &basis {
+            let r = splitmix64(&mut state) % p;
+            for (c, vi) in coefs.iter_mut().zip(v) {
+                *c = add_mod(*c, mul_mod(r, *vi, p), p);
+            }
+        }
+        let g = PrimeFieldPoly::reduced(p, coefs);
+        let half = g.pow_mod((p - 1) / 2, f).sub(&one);
+        factors = split(factors, &half);
+    }
+
+    factors
+}
+
+// replaces every factor h that g cuts properly with gcd(h, g) and its
+// cofactor
+fn split(factors: Vec<PrimeFieldPoly>, g: &PrimeFieldPoly) -> Vec<PrimeFieldPoly> {
+    let mut next = Vec::new();
+    for h in factors {
+        let d = h.gcd(g);
+        if d.coefs_.len() > 1 && d.coefs_.len() < h.coefs_.len() {
+            next.push(h.div_rem(&d).0);
+            next.push(d);
+        } else {
+            next.push(h);
+        }
+    }
+    next
+}
+
+// fixed seed, so factoring is deterministic
+fn splitmix64(state: &mut u64) -> u64 {
+    *state = state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+    let mut z = *state;
+    z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+    z ^ (z >> 31)
+}
+
+// monic irreducible factors of a monic f, repeated by multiplicity
+fn factor_monic(f: &PrimeFieldPoly) -> Vec<PrimeFieldPoly> {
+    if f.coefs_.len() == 1 {
+        return Vec::new();
+    }
+
+    let df = f.derivative();
+    if df.is_zero() {
+        // f(x) = g(x^p) = g(x)^p, since a^p = a for every a in GF(p)
+        let p = f.p as usize;
+        let g = PrimeFieldPoly::reduced(f.p, f.coefs_.iter().step_by(p).copied().collect());
+        let factors = factor_monic(&g);
+        return factors.iter().flat_map(|h| vec![h.clone(); p]).collect();
+    }
+
+    let g = f.gcd(&df);
+    if g.coefs_.len() > 1 {
+        let mut factors = factor_monic(&g);
+        factors.extend(factor_monic(&f.div_rem(&g).0));
+        return factors;
+    }
+
+    berlekamp(f)
+}
+
+// factors poly into monic irreducibles over GF(p), repeated according to
+// multiplicity and sorted by degree; a leading coefficient other than one
+// comes first as a constant factor
+pub fn factor_gf(p: u64, poly: &PrimeFieldPoly) -> Vec<PrimeFieldPoly> {
+    if poly.p != p {
+        panic!("polynomial is over GF({}), not GF({})", poly.p, p);
+    }
+    if poly.is_zero() {
+        panic!("cannot factor the zero polynomial");
+    }
+
+    let mut factors = factor_monic(&poly.to_monic());
+    factors.sort_by(|a, b| {
+        a.coefs_
+            .len()
+            .cmp(&b.coefs_.len())
+            .then_with(|| a.coefs_.iter().rev().cmp(b.coefs_.iter().rev()))
+    });
+
+    let lead = poly.leading_coefficient();
+    if lead != 1 {
+        factors.insert(0, PrimeFieldPoly::reduced(p, vec![lead]));
+    }
+    factors
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn gf7(coefs: Vec<u64>) -> PrimeFieldPoly {
+        PrimeFieldPoly::new(7, coefs)
+    }
+
+    #[test]
+    fn new() {
+        let poly = gf7(vec![9, 14, 3, 0, 7]);
+        assert_eq!(poly.coefficients(), &[2, 0, 3]);
+        assert_eq!(poly.degree(), Some(2));
+        assert_eq!(poly.modulus(), 7);
+        assert!(gf7(vec![7, 21]).is_zero());
+        assert_eq!(gf7(vec![]).degree(), None);
+    }
+
+    #[test]
+    fn large_modulus() {
+        // the largest prime below 2^64
+        let p = 18_446_744_073_709_551_557;
+        let big = |coefs: Vec<u64>| PrimeFieldPoly::new(p, coefs);
+
+        assert_eq!(
+            big(vec![p - 1, p - 2]).add(&big(vec![3, 1])),
+            big(vec![2, p - 1])
+        );
+        assert_eq!(
+            big(vec![1, 1]).sub(&big(vec![2, 3])),
+            big(vec![p - 1, p - 2])
+        );
+
+        // (x - 1)^2 = x^2 - 2x + 1
+        let square = big(vec![p - 1, 1]).mul(&big(vec![p - 1, 1]));
+        assert_eq!(square, big(vec![1, p - 2, 1]));
+        let (q, r) = square.div_rem(&big(vec![p - 1, 1]));
+        assert_eq!(q, big(vec![p - 1, 1]));
+        assert!(r.is_zero());
+    }
+
+    #[test]
+    fn is_prime() {
+        assert!(super::is_prime(2));
+        assert!(super::is_prime(7));
+        assert!(super::is_prime(4_294_967_291));
+        assert!(super::is_prime(18_446_744_073_709_551_557));
+        assert!(!super::is_prime(1));
+        assert!(!super::is_prime(561));
+        assert!(!super::is_prime(4_294_967_291 * 4_294_967_279));
+        assert!(!super::is_prime(u64::MAX));
+    }
+
+    #[test]
+    fn prime_factors() {
+        let sorted = |n: u64| {
+            let mut f = super::prime_factors(n);
+            f.sort();
+            f
+        };
+        assert!(sorted(1).is_empty());
+        assert_eq!(sorted(360), vec![2, 2, 2, 3, 3, 5]);
+        assert_eq!(sorted((1 << 61) - 1), vec![(1 << 61) - 1]);
+        assert_eq!(
+            sorted(2_147_483_629 * 2_147_483_647),
+            vec![2_147_483_629, 2_147_483_647]
+        );
+        assert_eq!(
+            sorted(1_000_003 * 1_000_003 * 7),
+            vec![7, 1_000_003, 1_000_003]
+        );
+        assert_eq!(sorted(u64::MAX), vec![3, 5, 17, 257, 641, 65537, 6_700_417]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn new_composite_modulus() {
+        PrimeFieldPoly::new(8, vec![1, 1]);
+    }
+
+    #[test]
+    fn add() {
+        assert_eq!(gf7(vec![3, 5, 6]).add(&gf7(vec![4, 3, 1])), gf7(vec![0, 1]));
+    }
+
+    #[test]
+    fn mul() {
+        // (x + 3)(2x + 5) = 2x^2 + 11x + 15
+        assert_eq!(gf7(vec![3, 1]).mul(&gf7(vec![5, 2])), gf7(vec![1, 4, 2]));
+        assert!(gf7(vec![3, 1]).mul(&gf7(vec![0])).is_zero());
+    }
+
+    #[test]
+    fn div_rem() {
+        // x^3 + 2x + 5 = (5x^2 + 3x + 2)(3x + 1) + 3
+        let (q, r) = gf7(vec![5, 2, 0, 1]).div_rem(&gf7(vec![1, 3]));
+        assert_eq!(q, gf7(vec![2, 3, 5]));
+        assert_eq!(r, gf7(vec![3]));
+
+        let (q, r) = gf7(vec![1, 4, 2]).div_rem(&gf7(vec![3, 1]));
+        assert_eq!(q, gf7(vec![5, 2]));
+        assert!(r.is_zero());
+
+        let (q, r) = gf7(vec![1, 4]).div_rem(&gf7(vec![3, 1, 1]));
+        assert!(q.is_zero());
+        assert_eq!(r, gf7(vec![1, 4]));
+    }
+
+    #[test]
+    fn factor_gf() {
+        let gf2 = |coefs: Vec<u64>| PrimeFieldPoly::new(2, coefs);
+        let product = |factors: &[PrimeFieldPoly], p: u64| {
+            factors
+                .iter()
+                .fold(PrimeFieldPoly::new(p, vec![1]), |acc, f| acc.mul(f))
+        };
+
+        // x^5 + x^4 + 1 = (x^2 + x + 1)(x^3 + x + 1)
+        let poly = gf2(vec![1, 0, 0, 0, 1, 1]);
+        let factors = super::factor_gf(2, &poly);
+        assert_eq!(factors, vec![gf2(vec![1, 1, 1]), gf2(vec![1, 1, 0, 1])]);
+        assert_eq!(product(&factors, 2), poly);
+
+        // x (x + 1)^2 (x^2 + x + 1)
+        let poly = gf2(vec![0, 1])
+            .mul(&gf2(vec![1, 1]))
+            .mul(&gf2(vec![1, 1]))
+            .mul(&gf2(vec![1, 1, 1]));
+        let factors = super::factor_gf(2, &poly);
+        assert_eq!(
+            factors,
+            vec![
+                gf2(vec![0, 1]),
+                gf2(vec![1, 1]),
+                gf2(vec![1, 1]),
+                gf2(vec![1, 1, 1])
+            ]
+        );
+        assert_eq!(product(&factors, 2), poly);
+
+        // x^4 + 1 = (x + 1)^4
+        let poly = gf2(vec![1, 0, 0, 0, 1]);
+        assert_eq!(super::factor_gf(2, &poly), vec![gf2(vec![1, 1]); 4]);
+
+        // 3 (x + 1)(x + 2)(x^2 + 1), x^2 + 1 is irreducible since 7 = 3 mod 4
+        let poly = gf7(vec![3])
+            .mul(&gf7(vec![1, 1]))
+            .mul(&gf7(vec![2, 1]))
+            .mul(&gf7(vec![1, 0, 1]));
+        let factors = super::factor_gf(7, &poly);
+        assert_eq!(
+            factors,
+            vec![
+                gf7(vec![3]),
+                gf7(vec![1, 1]),
+                gf7(vec![2, 1]),
+                gf7(vec![1, 0, 1])
+            ]
+        );
+        assert_eq!(product(&factors, 7), poly);
+
+        assert!(super::factor_gf(7, &gf7(vec![1])).is_empty());
+    }
+
+    #[test]
+    fn factor_gf_large_prime() {
+        // 2^61 - 1 = 3 mod 4, so x^2 + 1 and its shift x^2 + 2x + 2 are
+        // irreducible
+        let p = (1u64 << 61) - 1;
+        let gf = |coefs: Vec<u64>| PrimeFieldPoly::new(p, coefs);
+
+        // 5 (x + 3)^2 (x - 1)(x^2 + 1)(x^2 + 2x + 2)
+        let poly = gf(vec![5])
+            .mul(&gf(vec![3, 1]))
+            .mul(&gf(vec![3, 1]))
+            .mul(&gf(vec![p - 1, 1]))
+            .mul(&gf(vec![1, 0, 1]))
+            .mul(&gf(vec![2, 2, 1]));
+        let factors = super::factor_gf(p, &poly);
+        assert_eq!(
+            factors,
+            vec![
+                gf(vec![5]),
+                gf(vec![3, 1]),
+                gf(vec![3, 1]),
+                gf(vec![p - 1, 1]),
+                gf(vec![1, 0, 1]),
+                gf(vec![2, 2, 1])
+            ]
+        );
+        assert!(factors[1..].iter().all(|f| f.is_irreducible_gf(p)));
+    }
+
+    #[test]
+    fn is_irreducible_gf() {
+        let gf2 = |coefs: Vec<u64>| PrimeFieldPoly::new(2, coefs);
+
+        // x^2 + x + 1, x^3 + x + 1 and the aes polynomial x^8 + x^4 + x^3 + x + 1
+        assert!(gf2(vec![1, 1, 1]).is_irreducible_gf(2));
+        assert!(gf2(vec![1, 1, 0, 1]).is_irreducible_gf(2));
+        assert!(gf2(vec![1, 1, 0, 1, 1, 0, 0, 0, 1]).is_irreducible_gf(2));
+        assert!(gf2(vec![1, 1]).is_irreducible_gf(2));
+
+        // x^2 + 1 = (x + 1)^2 and x^5 + x^4 + 1 = (x^2 + x + 1)(x^3 + x + 1)
+        assert!(!gf2(vec![1, 0, 1]).is_irreducible_gf(2));
+        assert!(!gf2(vec![1, 0, 0, 0, 1, 1]).is_irreducible_gf(2));
+        // (x^2 + x + 1)^2 has no roots but still factors
+        assert!(!gf2(vec![1, 0, 1, 0, 1]).is_irreducible_gf(2));
+        assert!(!gf2(vec![1]).is_irreducible_gf(2));
+
+        assert!(gf7(vec![1, 0, 1]).is_irreducible_gf(7));
+        assert!(!gf7(vec![3, 0, 1]).is_irreducible_gf(7));
+    }
+
+    #[test]
+    #[should_panic]
+    fn mixed_fields() {
+        gf7(vec![1]).add(&PrimeFieldPoly::new(5, vec![1]));
+    }
+}
@@ -0,0 +1,74 @@
+use crate::Polynomial;
+use num::Complex;
+
+// finds all roots at once with the weierstrass (durand-kerner) iteration,
+// stops when no root moves by more than tol or after max_iter sweeps
+pub fn durand_kerner(p: &Polynomial<Complex<f64>>, max_iter: usize, tol: f64) -> Vec<Complex<f64>> {
+    let n = match p.degree() {
+        Some(n) if n > 0 => n,
+        _ => return Vec::new(),
+    };
+
+    let monic = p.scale(Complex::new(1.0, 0.0) / p.leading_coefficient());
+    let seed = Complex::new(0.4, 0.9);
+    let mut roots: Vec<Complex<f64>> = (0..n).map(|k| seed.powu(k as u32)).collect();
+
+    for _ in 0..max_iter {
+        let mut max_step: f64 = 0.0;
+
+        for i in 0..n {
+            let mut den = Complex::new(1.0, 0.0);
+            for j in 0..n {
+                if j != i {
+                    den *= roots[i] - roots[j];
+                }
+            }
+
+            let step = monic.eval_at(roots[i]) / den;
+            roots[i] -= step;
+            max_step = max_step.max(step.norm());
+        }
+
+        if max_step < tol {
+            break;
+        }
+    }
+
+    roots
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn c(re: f64, im: f64) -> Complex<f64> {
+        Complex::new(re, im)
+    }
+
+    fn assert_roots(found: &[Complex<f64>], expected: &[Complex<f64>]) {
+        assert_eq!(found.len(), expected.len());
+        for e in expected {
+            assert!(found.iter().any(|r| (r - e).norm() < 1e-8));
+        }
+    }
+
+    #[test]
+    fn conjugate_roots() {
+        // x^2 + 1
+        let p = Polynomial::new(vec![c(1.0, 0.0), c(0.0, 0.0), c(1.0, 0.0)]);
+        let roots = durand_kerner(&p, 500, 1e-14);
+        assert_roots(&roots, &[c(0.0, 1.0), c(0.0, -1.0)]);
+
+        // (x - 1)(x^2 - 2x + 5)
+        let p =
+            Polynomial::from_roots(&[c(1.0, 0.0), c(1.0, 2.0), c(1.0, -2.0)]).scale(c(3.0, 0.0));
+        let roots = durand_kerner(&p, 500, 1e-14);
+        assert_roots(&roots, &[c(1.0, 0.0), c(1.0, 2.0), c(1.0, -2.0)]);
+    }
+
+    #[test]
+    fn constant() {
+        let p = Polynomial::new(vec![c(2.0, 0.0)]);
+        assert!(durand_kerner(&p, 100, 1e-12).is_empty());
+    }
+}
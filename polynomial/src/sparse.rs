@@ -0,0 +1,162 @@
+use crate::Polynomial;
+use alloc::collections::BTreeMap;
+use alloc::vec;
+use alloc::vec::Vec;
+use num::Num;
+
+// keeps only the nonzero terms keyed by exponent, so x^1000 + 1 is two
+// entries instead of a 1001-long coefficient vector
+#[derive(Clone, Debug, PartialEq)]
+pub struct SparsePolynomial<T> {
+    terms: BTreeMap<usize, T>,
+}
+
+impl<T> SparsePolynomial<T>
+where
+    T: Clone
+        + core::cmp::PartialEq
+        + Default
+        + Num
+        + core::ops::Add<Output = T>
+        + core::ops::Mul<Output = T>
+        + core::ops::AddAssign<<T as core::ops::Mul>::Output>
+        + core::ops::Neg<Output = T>,
+{
+    // repeated exponents are summed, zero terms dropped
+    pub fn new(terms: Vec<(usize, T)>) -> Self {
+        let mut res = SparsePolynomial {
+            terms: BTreeMap::new(),
+        };
+        for (exp, coef) in terms {
+            res.add_term(exp, coef);
+        }
+        res
+    }
+
+    fn add_term(&mut self, exp: usize, coef: T) {
+        let sum = match self.terms.remove(&exp) {
+            Some(c) => c + coef,
+            None => coef,
+        };
+        if sum != T::zero() {
+            self.terms.insert(exp, sum);
+        }
+    }
+
+    pub fn from_dense(poly: &Polynomial<T>) -> Self {
+        SparsePolynomial::new(poly.coefficients().iter().cloned().enumerate().collect())
+    }
+
+    pub fn to_dense(&self) -> Polynomial<T> {
+        let len = self.degree().map_or(1, |d| d + 1);
+        let mut coefs = vec![T::default(); len];
+        for (exp, coef) in &self.terms {
+            coefs[*exp] = coef.clone();
+        }
+        Polynomial::new(coefs)
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.terms.is_empty()
+    }
+
+    pub fn degree(&self) -> Option<usize> {
+        self.terms.keys().next_back().copied()
+    }
+
+    pub fn coefficient(&self, exp: usize) -> T {
+        self.terms.get(&exp).cloned().unwrap_or_default()
+    }
+
+    // horner over the stored terms, bridging the gaps between exponents
+    // with powers of x
+    pub fn eval_at(&self, x: T) -> T {
+        let mut sum = T::default();
+        let mut prev = match self.degree() {
+            Some(d) => d,
+            None => return sum,
+        };
+
+        for (exp, coef) in self.terms.iter().rev() {
+            sum = sum * num::pow(x.clone(), prev - exp) + coef.clone();
+            prev = *exp;
+        }
+
+        sum * num::pow(x, prev)
+    }
+
+    pub fn add(&self, other: &Self) -> Self {
+        let mut res = self.clone();
+        for (exp, coef) in &other.terms {
+            res.add_term(*exp, coef.clone());
+        }
+        res
+    }
+
+    // one product per pair of stored terms, independent of the degree
+    pub fn mul(&self, other: &Self) -> Self {
+        let mut res = SparsePolynomial {
+            terms: BTreeMap::new(),
+        };
+        for (a_exp, a) in &self.terms {
+            for (b_exp, b) in &other.terms {
+                res.add_term(a_exp + b_exp, a.clone() * b.clone());
+            }
+        }
+        res
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn dense_round_trip() {
+        let dense = Polynomial::new(vec![1, 0, 0, -3, 0, 2]);
+        let sparse = SparsePolynomial::from_dense(&dense);
+        assert_eq!(sparse, SparsePolynomial::new(vec![(0, 1), (3, -3), (5, 2)]));
+        assert_eq!(sparse.to_dense(), dense);
+        assert_eq!(sparse.degree(), Some(5));
+        assert_eq!(sparse.coefficient(3), -3);
+        assert_eq!(sparse.coefficient(4), 0);
+
+        let zero = SparsePolynomial::new(vec![(4, 2), (4, -2)]);
+        assert!(zero.is_zero());
+        assert_eq!(zero.degree(), None);
+        assert_eq!(zero.to_dense(), Polynomial::zero());
+    }
+
+    #[test]
+    fn eval_at() {
+        let sparse = SparsePolynomial::new(vec![(40, 1i64), (0, 1)]);
+        assert_eq!(sparse.eval_at(2), (1 << 40) + 1);
+
+        let sparse = SparsePolynomial::new(vec![(7, 2i64), (3, -1)]);
+        assert_eq!(sparse.eval_at(3), sparse.to_dense().eval_at(3));
+        assert_eq!(SparsePolynomial::<i64>::new(vec![]).eval_at(5), 0);
+    }
+
+    #[test]
+    fn add() {
+        let a = SparsePolynomial::new(vec![(1000, 1), (0, 1)]);
+        let b = SparsePolynomial::new(vec![(1000, -1), (3, 4)]);
+        assert_eq!(a.add(&b), SparsePolynomial::new(vec![(3, 4), (0, 1)]));
+        assert_eq!(a.add(&b).to_dense(), a.to_dense().add(&b.to_dense()));
+    }
+
+    #[test]
+    fn mul() {
+        // (x^1000 + 1)(x^1000 - 1)
+        let a = SparsePolynomial::new(vec![(1000, 1), (0, 1)]);
+        let b = SparsePolynomial::new(vec![(1000, 1), (0, -1)]);
+        let product = a.mul(&b);
+        assert_eq!(product, SparsePolynomial::new(vec![(2000, 1), (0, -1)]));
+        assert_eq!(product.to_dense(), a.to_dense().mul(&b.to_dense()));
+
+        let a = SparsePolynomial::new(vec![(3, 2), (1, -1), (0, 5)]);
+        let b = SparsePolynomial::new(vec![(4, 1), (2, 3)]);
+        assert_eq!(a.mul(&b).to_dense(), a.to_dense().mul(&b.to_dense()));
+        assert!(a.mul(&SparsePolynomial::new(vec![])).is_zero());
+    }
+}